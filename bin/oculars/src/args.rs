@@ -1,8 +1,13 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use upgrades::hardfork::HardFork;
 
 /// Utility for getting EVM instruction information.
 #[derive(Parser)]
 pub struct Cli {
+    /// Disable colored output. Colors are also disabled if the `NO_COLOR` environment variable is
+    /// set.
+    #[arg(long, global = true)]
+    pub no_color: bool,
     /// CLI subcommand.
     #[command(subcommand)]
     pub command: Command,
@@ -12,10 +17,95 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Command {
     /// List all EVM mnemonics.
-    Mnemonics,
+    Mnemonics {
+        /// Wrap descriptions to fit into this many columns.
+        #[arg(long)]
+        columns: Option<usize>,
+        /// Only list mnemonics introduced in or after this hard fork (e.g. `cancun`).
+        #[arg(long, value_parser = parse_hard_fork)]
+        since: Option<HardFork>,
+        /// Only list mnemonics introduced in or before this hard fork (e.g. `london`).
+        #[arg(long, value_parser = parse_hard_fork)]
+        until: Option<HardFork>,
+    },
     /// Get information about a specific mnemonic.
     Mnemonic {
         /// Name of the mnemonic.
         name: String,
     },
+    /// Print a table of all 256 opcodes.
+    Table,
+    /// Disassemble EVM bytecode.
+    Disassemble {
+        /// Hex encoded bytecode (e.g. `0x6001`).
+        #[arg(long, required_unless_present = "rpc", conflicts_with = "rpc")]
+        hex: Option<String>,
+        /// URL of a JSON-RPC node to fetch the bytecode from.
+        #[arg(long, requires = "address")]
+        rpc: Option<String>,
+        /// Address of the contract to fetch from the JSON-RPC node.
+        #[arg(long, requires = "rpc")]
+        address: Option<String>,
+    },
+    /// Print the hard fork schedule of a chain.
+    Forks {
+        /// Name of the chain.
+        #[arg(value_enum, default_value_t = ChainName::Mainnet)]
+        chain: ChainName,
+    },
+    /// Print the EIPs and mnemonics added and removed between two hard forks.
+    ForkDiff {
+        /// Name of the hard fork to diff from (e.g. `london`).
+        #[arg(value_parser = parse_hard_fork)]
+        from: HardFork,
+        /// Name of the hard fork to diff to (e.g. `cancun`).
+        #[arg(value_parser = parse_hard_fork)]
+        to: HardFork,
+    },
+    /// List all EIPs known to oculars.
+    Eips,
+}
+
+/// Parse a hard fork by its name, ignoring case, spaces, dashes and underscores.
+fn parse_hard_fork(name: &str) -> Result<HardFork, String> {
+    /// Normalizes a hard fork name for comparison.
+    fn normalize(name: &str) -> String {
+        name.chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .collect::<String>()
+            .to_lowercase()
+    }
+
+    let normalized = normalize(name);
+
+    HardFork::VARIANTS
+        .iter()
+        .copied()
+        .find(|fork| normalize(&fork.to_string()) == normalized)
+        .ok_or_else(|| format!("unknown hard fork `{name}`"))
+}
+
+/// Known chains.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ChainName {
+    /// Ethereum Mainnet.
+    Mainnet,
+    /// Ethereum Classic Testnet Morden.
+    Morden,
+    /// Ethereum Ropsten testnet.
+    Ropsten,
+    /// Ethereum Kovan testnet.
+    Kovan,
+    /// Ethereum Rinkeby testnet.
+    Rinkeby,
+    /// Ethereum Görli testnet.
+    Goerli,
+    /// OP Mainnet (Optimism).
+    Optimism,
+    /// Base.
+    Base,
+    /// Arbitrum One.
+    ArbitrumOne,
+    /// Polygon proof-of-stake chain.
+    Polygon,
 }
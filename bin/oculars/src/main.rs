@@ -6,45 +6,166 @@ mod args;
 mod mnemonics;
 
 use anyhow::Context;
-use asm::Mnemonic;
+use chains::{
+    Chain, Goerli, Kovan, Mainnet, Morden, Rinkeby, Ropsten,
+    l2::{ArbitrumOne, Base, Optimism, PolygonPoS},
+};
 use clap::Parser as _;
+use dasm::{assembly::Assembly, disassembler::Disassembler, source::rpc::RpcSource};
 use minus::Pager;
-use std::fmt::Write;
+use std::{
+    fmt::Write,
+    io::{ErrorKind, IsTerminal as _, Write as _},
+};
+use upgrades::{
+    execution::Latest,
+    hardfork::{HardFork, HardForkMeta, schedule},
+};
 
 use crate::{
-    args::{Cli, Command},
-    mnemonics::{MnemonicDescription, get_mnemonic_by_name},
+    args::{ChainName, Cli, Command},
+    mnemonics::{
+        MnemonicDescription, format_opcode_table, get_mnemonic_by_name, mnemonics_introduced_within,
+    },
 };
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
+    if cli.no_color || std::env::var_os("NO_COLOR").is_some() {
+        colored::control::set_override(false);
+    }
+
     match cli.command {
-        Command::Mnemonics => list_mnemonics()?,
+        Command::Mnemonics {
+            columns,
+            since,
+            until,
+        } => list_mnemonics(columns, since, until)?,
         Command::Mnemonic { name } => display_mnemonic_info_by_name(&name)?,
+        Command::Table => write_stdout(&format_opcode_table())?,
+        Command::Disassemble { hex, rpc, address } => match (hex, rpc, address) {
+            (Some(hex), _, _) => disassemble_hex(&hex)?,
+            (None, Some(rpc), Some(address)) => disassemble_rpc(&rpc, &address)?,
+            _ => unreachable!("clap requires either `--hex` or `--rpc` with `--address`"),
+        },
+        Command::Forks { chain } => write_stdout(&format_fork_schedule(chain))?,
+        Command::ForkDiff { from, to } => write_stdout(&upgrades::diff(from, to).to_string())?,
+        Command::Eips => write_stdout(&format_eips())?,
     }
 
     Ok(())
 }
 
-/// List a list of all mnemonics.
-fn list_mnemonics() -> anyhow::Result<()> {
-    let mut pager = Pager::new();
+/// List all mnemonics introduced within the `since..=until` hard fork range, wrapping descriptions
+/// to fit into `columns` if set.
+fn list_mnemonics(
+    columns: Option<usize>,
+    since: Option<HardFork>,
+    until: Option<HardFork>,
+) -> anyhow::Result<()> {
+    let mut output = String::new();
 
-    for mnemonic in Mnemonic::VARIANTS {
-        writeln!(pager, "{}\n", mnemonic.format_human())
+    for mnemonic in mnemonics_introduced_within(since, until) {
+        writeln!(output, "{}\n", mnemonic.format_human(columns))
             .context("failed to write mnemonic info")?;
     }
 
-    minus::page_all(pager).context("failed to display mnemonic info")?;
-
-    Ok(())
+    page(&output).context("failed to display mnemonic info")
 }
 
 /// Display information about a mnemonic by its name.
 fn display_mnemonic_info_by_name(name: &str) -> anyhow::Result<()> {
     let mnemonic = get_mnemonic_by_name(name)?;
-    println!("{}", mnemonic.format_human());
+
+    write_stdout(&format!("{}\n", mnemonic.format_human(None)))
+}
+
+/// Disassemble hex encoded bytecode and display the assembly.
+fn disassemble_hex(hex: &str) -> anyhow::Result<()> {
+    let assembly = Disassembler::<Latest>::default()
+        .disassemble_hex(hex)
+        .with_context(|| format!("failed to disassemble \"{hex}\""))?;
+
+    page_assembly(&assembly)
+}
+
+/// Fetch bytecode of a contract from a JSON-RPC node, disassemble it and display the assembly.
+fn disassemble_rpc(rpc: &str, address: &str) -> anyhow::Result<()> {
+    let assembly = Disassembler::<Latest>::default()
+        .disassemble_from_source(RpcSource::new(rpc, address))
+        .with_context(|| format!("failed to disassemble the code of {address}"))?;
+
+    page_assembly(&assembly)
+}
+
+/// Display assembly through a pager.
+fn page_assembly(assembly: &Assembly) -> anyhow::Result<()> {
+    page(&assembly.to_string()).context("failed to display assembly")
+}
+
+/// Display output through a pager if stdout is a terminal. Otherwise (e.g. when piped to a file
+/// or another program) the output is written as is (see [`write_stdout`]), without any pager
+/// control sequences.
+fn page(output: &str) -> anyhow::Result<()> {
+    if !std::io::stdout().is_terminal() {
+        return write_stdout(output);
+    }
+
+    let pager = Pager::new();
+    pager.push_str(output)?;
+    minus::page_all(pager)?;
 
     Ok(())
 }
+
+/// Write output to stdout. A reader that closes the pipe early (e.g. `head`) is not an error.
+fn write_stdout(output: &str) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+
+    match stdout
+        .write_all(output.as_bytes())
+        .and_then(|()| stdout.flush())
+    {
+        Err(error) if error.kind() == ErrorKind::BrokenPipe => Ok(()),
+        result => result.context("failed to write to stdout"),
+    }
+}
+
+/// Format all known EIPs, one EIP per line.
+fn format_eips() -> String {
+    upgrades::all_eips()
+        .iter()
+        .fold(String::new(), |mut output, (number, title)| {
+            _ = writeln!(output, "EIP-{number}: {title}");
+            output
+        })
+}
+
+/// Format the hard fork schedule of a chain.
+fn format_fork_schedule(chain: ChainName) -> String {
+    match chain {
+        ChainName::Mainnet => format_schedule::<Mainnet>(),
+        ChainName::Morden => format_schedule::<Morden>(),
+        ChainName::Ropsten => format_schedule::<Ropsten>(),
+        ChainName::Kovan => format_schedule::<Kovan>(),
+        ChainName::Rinkeby => format_schedule::<Rinkeby>(),
+        ChainName::Goerli => format_schedule::<Goerli>(),
+        ChainName::Optimism => format_schedule::<Optimism>(),
+        ChainName::Base => format_schedule::<Base>(),
+        ChainName::ArbitrumOne => format_schedule::<ArbitrumOne>(),
+        ChainName::Polygon => format_schedule::<PolygonPoS>(),
+    }
+}
+
+/// Format the hard fork schedule of the chain `C`, one fork per line.
+fn format_schedule<C: Chain>() -> String
+where
+    HardFork: HardForkMeta<C>,
+{
+    schedule::<C>().fold(String::new(), |mut output, (fork, activation)| {
+        let activation = activation.map_or_else(|| "n/a".to_string(), |a| a.to_string());
+        _ = writeln!(output, "{fork:<18} {activation}");
+        output
+    })
+}
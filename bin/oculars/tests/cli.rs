@@ -0,0 +1,97 @@
+//! Tests running the `oculars` binary.
+
+use std::process::Command;
+
+#[test]
+fn piped_output_is_not_paged() {
+    let output = Command::new(env!("CARGO_BIN_EXE_oculars-bin"))
+        .args(["--no-color", "mnemonics", "--since", "cancun"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    // The pager switches to the alternate screen and moves the cursor using escape sequences,
+    // while piped output must be plain text.
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.contains('\x1b'));
+    assert!(stdout.starts_with("* BLOBHASH - "));
+    assert!(stdout.contains("* MCOPY - "));
+}
+
+#[test]
+fn colors_can_be_disabled() {
+    // Colors are forced with `CLICOLOR_FORCE`, since they are disabled for piped output anyway.
+    let mnemonics = |args: &[&str], no_color: bool| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_oculars-bin"));
+        command
+            .args(args)
+            .arg("mnemonics")
+            .env("CLICOLOR_FORCE", "1")
+            .env_remove("NO_COLOR");
+        if no_color {
+            command.env("NO_COLOR", "1");
+        }
+
+        let output = command.output().unwrap();
+        assert!(output.status.success());
+        output.stdout
+    };
+
+    assert!(mnemonics(&[], false).contains(&b'\x1b'));
+    assert!(!mnemonics(&[], true).contains(&b'\x1b'));
+    assert!(!mnemonics(&["--no-color"], false).contains(&b'\x1b'));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn write_errors_are_reported() {
+    for args in [
+        &["mnemonics"][..],
+        &["mnemonic", "add"],
+        &["table"],
+        &["forks"],
+        &["fork-diff", "london", "cancun"],
+        &["eips"],
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_oculars-bin"))
+            .args(args)
+            .stdout(std::fs::File::create("/dev/full").unwrap())
+            .output()
+            .unwrap();
+
+        assert!(!output.status.success(), "{args:?}");
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("failed to write to stdout"), "{args:?}");
+        assert!(!stderr.contains("panicked"), "{args:?}");
+    }
+}
+
+#[test]
+fn piped_assembly_is_not_paged() {
+    let output = Command::new(env!("CARGO_BIN_EXE_oculars-bin"))
+        .args(["disassemble", "--hex", "0x6080604052"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "0x0000 PUSH1 0x80\n0x0002 PUSH1 0x40\n0x0004 MSTORE\n"
+    );
+}
+
+#[test]
+fn eips_are_listed() {
+    let output = Command::new(env!("CARGO_BIN_EXE_oculars-bin"))
+        .arg("eips")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("EIP-2: Homestead Hard-fork Changes\n"));
+    assert!(stdout.contains("\nEIP-1559: Fee market change for ETH 1.0 chain\n"));
+}
@@ -2,7 +2,7 @@
 
 use thiserror::Error;
 
-use crate::{Mnemonic, OpCode};
+use crate::{Mnemonic, OpCode, instruction::Operand};
 
 /// An EVM assembly instruction.
 pub trait AssemblyInstruction: Sized {
@@ -143,6 +143,20 @@ pub trait AssemblyInstruction: Sized {
         self.opcode().is_control_flow()
     }
 
+    /// Returns the operand of this instruction.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::{AssemblyInstruction, instruction::{Gas, Operand, Push}};
+    /// assert_eq!(Gas.operand(), Operand::None);
+    /// assert_eq!(Push::new([0xA, 0xB]).operand(), Operand::Immediate(&[0xA, 0xB]));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn operand(&self) -> Operand<'_> {
+        Operand::None
+    }
+
     /// Assembles this instruction into its byte representation.
     ///
     /// # Example
@@ -173,7 +187,8 @@ pub trait AssemblyInstruction: Sized {
 }
 
 /// Errors that can happen during instruction disassembly.
-#[derive(Debug, Error)]
+#[non_exhaustive]
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
 pub enum DisassemblyError {
     /// An unexpected opcode was encountered.
     #[error("unexpected opcode: expected `{expected}`, got `{got}`")]
@@ -213,3 +228,38 @@ pub(crate) fn verify_opcode(bytes: &[u8], expected: u8) -> Result<(), Disassembl
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Instruction, instruction::Push};
+
+    #[test]
+    fn disassembly_error_fmt() {
+        let error = Push::<2>::disassemble(&[0x61, 0x01]).unwrap_err();
+        assert_eq!(
+            error,
+            DisassemblyError::UnexpectedLength {
+                got: 2,
+                expected: 3
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "unexpected byte sequence length: expected `3`, got `2`"
+        );
+
+        let error = Push::<2>::disassemble(&[0x60, 0x01, 0x02]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected opcode: expected `97`, got `96`"
+        );
+    }
+
+    #[test]
+    fn disassembly_error_is_std_error() {
+        fn assert_error<E: std::error::Error + Send + Sync + 'static>(_: &E) {}
+
+        assert_error(&Instruction::disassemble(&[]).unwrap_err());
+    }
+}
@@ -30,6 +30,15 @@ macro_rules! define_mnemonics {
             /// ```
             pub const VARIANTS: &[Self] = &[$(Self::$name),+];
 
+            /// The number of mnemonic variants.
+            ///
+            /// # Example
+            /// ```
+            /// # use oculars_asm::Mnemonic;
+            /// assert_eq!(Mnemonic::COUNT, Mnemonic::VARIANTS.len());
+            /// ```
+            pub const COUNT: usize = Self::VARIANTS.len();
+
             /// Attempts to parse a byte as a mnemonic. Returns [`None`] if the byte is not a known
             /// mnemonic.
             ///
@@ -48,6 +57,28 @@ macro_rules! define_mnemonics {
                     _ => None
                 }
             }
+
+            /// Attempts to parse a mnemonic from its name. Returns [`None`] if the name does not
+            /// match any known mnemonic.
+            ///
+            /// # Note
+            /// The name is case sensitive.
+            ///
+            /// # Example
+            /// ```
+            /// # use oculars_asm::Mnemonic;
+            /// assert_eq!(Mnemonic::from_name("GAS"), Some(Mnemonic::GAS));
+            /// assert_eq!(Mnemonic::from_name("gas"), None);
+            /// ```
+            #[must_use]
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $(
+                        stringify!($name) => Some(Self::$name),
+                    )+
+                    _ => None
+                }
+            }
         }
 
         impl std::fmt::Display for Mnemonic {
@@ -346,7 +377,7 @@ mod tests {
     use super::{instruction::Add, mnemonic::Mnemonic};
     use crate::{AssemblyInstruction, OpCode};
 
-    #[expect(non_camel_case_types, reason = "le funny")]
+    #[expect(non_camel_case_types, clippy::upper_case_acronyms, reason = "le funny")]
     #[test]
     fn define_mnemonics_works() {
         define_mnemonics!(
@@ -356,11 +387,22 @@ mod tests {
 
         assert_eq!(Mnemonic::VARIANTS[0], Mnemonic::PAY_VITALIK);
         assert_eq!(Mnemonic::VARIANTS[1], Mnemonic::REVERT);
+        assert_eq!(Mnemonic::COUNT, 2);
         assert_eq!(Mnemonic::from_byte(0xF), Some(Mnemonic::PAY_VITALIK));
         assert_eq!(Mnemonic::from_byte(0x5A), None);
+        assert_eq!(
+            Mnemonic::from_name("PAY_VITALIK"),
+            Some(Mnemonic::PAY_VITALIK)
+        );
+        assert_eq!(Mnemonic::from_name("GAS"), None);
         assert_eq!(Mnemonic::PAY_VITALIK.to_string(), "PAY_VITALIK");
     }
 
+    #[test]
+    fn mnemonic_count() {
+        assert_eq!(Mnemonic::COUNT, Mnemonic::VARIANTS.len());
+    }
+
     #[test]
     fn instructions_are_defined_properly() {
         assert_eq!(Mnemonic::from_byte(0x1), Some(Mnemonic::ADD));
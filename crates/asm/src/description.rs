@@ -0,0 +1,323 @@
+//! Human readable mnemonic descriptions.
+
+use crate::Mnemonic;
+
+impl Mnemonic {
+    /// Returns a human readable description of this mnemonic.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::STOP.description(), "Halts execution.");
+    /// ```
+    #[expect(clippy::too_many_lines, reason = "can't split this up")]
+    #[must_use]
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::STOP => "Halts execution.",
+            Self::ADD => {
+                "Adds the top two elements of the stack together, and pushes the result back on the stack."
+            }
+            Self::MUL => {
+                "Multiply the top two elements of the stack, and pushes the result back on the stack."
+            }
+            Self::SUB => {
+                "Subtracts the top two elements of the stack, and pushes the result back on the stack."
+            }
+            Self::DIV => {
+                "Integer division of the top two elements of the stack. Pushes the result back on the stack."
+            }
+            Self::SDIV => {
+                "Signed integer division of the top two elements of the stack. Pushes the result back on the stack."
+            }
+            Self::MOD => {
+                "Modulo remainder of the top two elements of the stack. Pushes the result back on the stack."
+            }
+            Self::SMOD => {
+                "Signed modulo remainder of the top two elements of the stack. Pushes the result back on the stack."
+            }
+            Self::ADDMOD => {
+                "Modulo addition of the top 2 elements with the 3rd element. Pushes the result back on the stack."
+            }
+            Self::MULMOD => {
+                "Modulo multiplication of the top 2 elements with the 3rd element. Pushes the result back on the stack."
+            }
+            Self::EXP => {
+                "Exponential operation of the top 2 elements. Pushes the result back on the stack."
+            }
+            Self::SIGNEXTEND => {
+                "Sign extend operation. In other words, extend a signed number which fits in N bytes to 32 bytes."
+            }
+            Self::LT => {
+                "Checks if the top element is less than the next top element. Pushes the result back on the stack."
+            }
+            Self::GT => {
+                "Checks if the top element is greater than the next top element. Pushes the result back on the stack."
+            }
+            Self::SLT => "Signed less-than comparison.",
+            Self::SGT => "Signed greater-than comparison.",
+            Self::EQ => {
+                "Checks if the top element is equal to the next top element. Pushes the result back on the stack."
+            }
+            Self::ISZERO => {
+                "Checks if the top element is equal to 0. Pushes the result back on the stack."
+            }
+            Self::AND => {
+                "Bitwise AND operation of the top 2 elements of the stack. Pushes the result back on the stack."
+            }
+            Self::OR => {
+                "Bitwise OR operation of the top 2 elements of the stack. Pushes the result back on the stack."
+            }
+            Self::XOR => {
+                "Bitwise XOR operation of the top 2 elements of the stack. Pushes the result back on the stack."
+            }
+            Self::NOT => {
+                "Bitwise NOT operation of the top element of the stack. Pushes the result back on the stack."
+            }
+            Self::BYTE => {
+                "For a word (defined by next top element of the stack), retrieve the Nth byte (0-indexed and defined by top element of stack) from the left (most significant) to right (least significant)."
+            }
+            Self::SHL => {
+                "Logical shift left operation of the top 2 elements of the stack. Pushes the result back on the stack."
+            }
+            Self::SHR => {
+                "Logical shift right  operation of the top 2 elements of the stack. Pushes the result back on the stack."
+            }
+            Self::SAR => {
+                "Arithmetic shift right operation of the top 2 elements of the stack. Pushes the result back on the stack."
+            }
+            Self::KECCAK256 => "Pushes to the stack the Keccak-256 hash of a region of memory.",
+            Self::ADDRESS => "Pushes the address of the current executing account to the stack.",
+            Self::BALANCE => "Pushes the balance of the given account onto the stack.",
+            Self::ORIGIN => {
+                "Pushes the address of the original transaction sender to the stack. The origin address can only be an EOA."
+            }
+            Self::CALLER => "Pushes the address of the caller onto the stack.",
+            Self::CALLVALUE => "Push the value (in wei) sent with the call onto the stack.",
+            Self::CALLDATALOAD => {
+                "Push a word (32 bytes) of the input data belonging to the current environment onto the stack."
+            }
+            Self::CALLDATASIZE => {
+                "Push the size of input data in current environment onto the stack."
+            }
+            Self::CALLDATACOPY => {
+                "Copy a portion of the input data in current environment to memory."
+            }
+            Self::CODESIZE => {
+                "Push the size of code running in current environment onto the stack."
+            }
+            Self::CODECOPY => "Copy a portion of the code in current environment to memory.",
+            Self::GASPRICE => "Push the gas price used in current environment onto the stack.",
+            Self::EXTCODESIZE => "Push the code size of a given account onto the stack.",
+            Self::EXTCODECOPY => "Copy a portion of an account's code to memory.",
+            Self::RETURNDATASIZE => "Pushes the size of the return data buffer onto the stack.",
+            Self::RETURNDATACOPY => "Copies data from the return data buffer code to memory.",
+            Self::EXTCODEHASH => "Returns the Keccak-256 hash of a contract’s bytecode.",
+            Self::BLOCKHASH => {
+                "Push the hash of one of the 256 most recent complete blocks onto the stack. The block number to hash is present at the top of the stack."
+            }
+            Self::COINBASE => {
+                "Push the current block's beneficiary address (address of the block miner) onto the stack."
+            }
+            Self::TIMESTAMP => {
+                "Push the current block's timestamp onto the stack. Here the timestamp being referred is actually the unix timestamp in seconds."
+            }
+            Self::NUMBER => "Push the current block's number onto the stack.",
+            Self::PREVRANDAO => {
+                "Push the prev_randao value onto the stack. The prev_randao value is the random output of the beacon chain's randomness oracle for the previous block."
+            }
+            Self::GASLIMIT => "Push the current block's gas limit onto the stack.",
+            Self::CHAINID => "Push the chain id onto the stack.",
+            Self::SELFBALANCE => "Pushes the balance of the current address to the stack.",
+            Self::BASEFEE => "Pushes the base fee of the current block on to the stack.",
+            Self::BLOBHASH => "Pushes the versioned hash at a particular index on to the stack.",
+            Self::BLOBBASEFEE => "Pushes the blob base fee on to the stack.",
+            Self::POP => "Remove item from stack.",
+            Self::MLOAD => "Loads a word from memory.",
+            Self::MSTORE => "Stores a word to memory.",
+            Self::MSTORE8 => "Stores a byte to memory.",
+            Self::SLOAD => "Loads a word from storage of the current account.",
+            Self::SSTORE => "Stores a word to storage of the current account.",
+            Self::JUMP => {
+                "Alters the program counter to the location specified by the top of the stack."
+            }
+            Self::JUMPI => {
+                "Alters the program counter to the specified location if and only if a condition is true. If the condition is not true, then the program counter would increase only by one."
+            }
+            Self::PC => {
+                "Pushes onto the stack the value of the program counter after reaching the current instruction and without increasing it for the next instruction."
+            }
+            Self::MSIZE => "Pushes the size of active memory in bytes onto the stack.",
+            Self::GAS => {
+                "Pushes the amount of available gas (including the corresponding reduction for the cost of this instruction) onto the stack."
+            }
+            Self::JUMPDEST => "Marks a valid destination for jumps.",
+            Self::TLOAD => "Loads a word from transient storage of the current acount",
+            Self::TSTORE => "Stores a word to transient storage of the current acount",
+            Self::MCOPY => "Copies the bytes in memory from one location to another.",
+            Self::PUSH0 => "Pushes a zero onto the stack.",
+            Self::PUSH1 => "Pushes a 1-byte immediate onto the stack.",
+            Self::PUSH2 => "Pushes a 2-byte immediate onto the stack.",
+            Self::PUSH3 => "Pushes a 3-byte immediate onto the stack.",
+            Self::PUSH4 => "Pushes a 4-byte immediate onto the stack.",
+            Self::PUSH5 => "Pushes a 5-byte immediate onto the stack.",
+            Self::PUSH6 => "Pushes a 6-byte immediate onto the stack.",
+            Self::PUSH7 => "Pushes a 7-byte immediate onto the stack.",
+            Self::PUSH8 => "Pushes a 8-byte immediate onto the stack.",
+            Self::PUSH9 => "Pushes a 9-byte immediate onto the stack.",
+            Self::PUSH10 => "Pushes a 10-byte immediate onto the stack.",
+            Self::PUSH11 => "Pushes a 11-byte immediate onto the stack.",
+            Self::PUSH12 => "Pushes a 12-byte immediate onto the stack.",
+            Self::PUSH13 => "Pushes a 13-byte immediate onto the stack.",
+            Self::PUSH14 => "Pushes a 14-byte immediate onto the stack.",
+            Self::PUSH15 => "Pushes a 15-byte immediate onto the stack.",
+            Self::PUSH16 => "Pushes a 16-byte immediate onto the stack.",
+            Self::PUSH17 => "Pushes a 17-byte immediate onto the stack.",
+            Self::PUSH18 => "Pushes a 18-byte immediate onto the stack.",
+            Self::PUSH19 => "Pushes a 19-byte immediate onto the stack.",
+            Self::PUSH20 => "Pushes a 20-byte immediate onto the stack.",
+            Self::PUSH21 => "Pushes a 21-byte immediate onto the stack.",
+            Self::PUSH22 => "Pushes a 22-byte immediate onto the stack.",
+            Self::PUSH23 => "Pushes a 23-byte immediate onto the stack.",
+            Self::PUSH24 => "Pushes a 24-byte immediate onto the stack.",
+            Self::PUSH25 => "Pushes a 25-byte immediate onto the stack.",
+            Self::PUSH26 => "Pushes a 26-byte immediate onto the stack.",
+            Self::PUSH27 => "Pushes a 27-byte immediate onto the stack.",
+            Self::PUSH28 => "Pushes a 28-byte immediate onto the stack.",
+            Self::PUSH29 => "Pushes a 29-byte immediate onto the stack.",
+            Self::PUSH30 => "Pushes a 30-byte immediate onto the stack.",
+            Self::PUSH31 => "Pushes a 31-byte immediate onto the stack.",
+            Self::PUSH32 => "Pushes a 32-byte immediate onto the stack.",
+            Self::DUP1 => "Duplicate the 1st stack item",
+            Self::DUP2 => {
+                "Duplicate the 2nd stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP3 => {
+                "Duplicate the 3rd stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP4 => {
+                "Duplicate the 4th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP5 => {
+                "Duplicate the 5th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP6 => {
+                "Duplicate the 6th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP7 => {
+                "Duplicate the 7th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP8 => {
+                "Duplicate the 8th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP9 => {
+                "Duplicate the 9th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP10 => {
+                "Duplicate the 10th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP11 => {
+                "Duplicate the 11th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP12 => {
+                "Duplicate the 12th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP13 => {
+                "Duplicate the 13th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP14 => {
+                "Duplicate the 14th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP15 => {
+                "Duplicate the 15th stack item (from top of the stack) to the top of stack."
+            }
+            Self::DUP16 => {
+                "Duplicate the 16th stack item (from top of the stack) to the top of stack."
+            }
+            Self::SWAP1 => {
+                "Swap the top and the 1st element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP2 => {
+                "Swap the top and the 2nd element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP3 => {
+                "Swap the top and the 3rd element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP4 => {
+                "Swap the top and the 4th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP5 => {
+                "Swap the top and the 5th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP6 => {
+                "Swap the top and the 6th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP7 => {
+                "Swap the top and the 7th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP8 => {
+                "Swap the top and the 8th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP9 => {
+                "Swap the top and the 9th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP10 => {
+                "Swap the top and the 10th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP11 => {
+                "Swap the top and the 11th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP12 => {
+                "Swap the top and the 12th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP13 => {
+                "Swap the top and the 13th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP14 => {
+                "Swap the top and the 14th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP15 => {
+                "Swap the top and the 15th element of the stack, where the top of the stack is position zero."
+            }
+            Self::SWAP16 => {
+                "Swap the top and the 16th element of the stack, where the top of the stack is position zero."
+            }
+            Self::LOG0 => "Append log record with no topics.",
+            Self::LOG1 => "Append log record with one topic.",
+            Self::LOG2 => "Append log record with two topics.",
+            Self::LOG3 => "Append log record with three topics.",
+            Self::LOG4 => "Append log record with four topics.",
+            Self::CREATE => "Creates a new account with associated code.",
+            Self::CALL => "Message-call into an account.",
+            Self::CALLCODE => "Message-call into this account with alternative account’s code.",
+            Self::RETURN => "Halts execution returning output data.",
+            Self::DELEGATECALL => {
+                "Message-call into this account with an alternative account’s code, but persisting the current values for sender and value."
+            }
+            Self::CREATE2 => {
+                "Creates a new account with associated code. It's similar to CREATE opcode except that the address of new account depends on the init_code instead of the nonce of sender."
+            }
+            Self::STATICCALL => "Static message-call into an account.",
+            Self::REVERT => {
+                "Halt execution reverting state changes but returning data and remaining gas."
+            }
+            Self::INVALID => "Designated invalid instruction.",
+            Self::SELFDESTRUCT => "Halt execution and register account for later deletion.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_mnemonic_has_a_description() {
+        for mnemonic in Mnemonic::VARIANTS {
+            let description = mnemonic.description();
+
+            assert!(!description.is_empty(), "{mnemonic}");
+            assert!(!description.starts_with("Unknown"), "{mnemonic}");
+        }
+    }
+}
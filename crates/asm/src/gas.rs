@@ -0,0 +1,410 @@
+//! Static gas costs of mnemonics.
+
+use crate::Mnemonic;
+
+/// A fee tier of the yellow paper, which groups mnemonics with the same constant gas cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FeeTier {
+    /// `W_zero`, costs nothing.
+    Zero,
+    /// `W_base`, costs 2 gas.
+    Base,
+    /// `W_verylow`, costs 3 gas.
+    VeryLow,
+    /// `W_low`, costs 5 gas.
+    Low,
+    /// `W_mid`, costs 8 gas.
+    Mid,
+    /// `W_high`, costs 10 gas.
+    High,
+}
+
+impl FeeTier {
+    /// Returns the gas charged for mnemonics in this fee tier.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::FeeTier;
+    /// assert_eq!(FeeTier::VeryLow.gas(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn gas(self) -> u64 {
+        match self {
+            Self::Zero => 0,
+            Self::Base => 2,
+            Self::VeryLow => 3,
+            Self::Low => 5,
+            Self::Mid => 8,
+            Self::High => 10,
+        }
+    }
+}
+
+/// The constant gas cost of a mnemonic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cost {
+    /// The gas cost of a fee tier.
+    Tier(FeeTier),
+    /// A gas cost that is not defined by a fee tier.
+    Fixed(u64),
+}
+
+impl Mnemonic {
+    /// Returns the constant amount of gas charged for executing this mnemonic as of the latest
+    /// execution upgrade.
+    ///
+    /// For mnemonics with a dynamic gas component (see [`Mnemonic::has_dynamic_gas`]) this is
+    /// only the static part of the cost, e.g. the warm account access cost for `BALANCE` or the
+    /// cost of a single word memory access for `MLOAD`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::ADD.base_gas_cost(), 3);
+    /// assert_eq!(Mnemonic::JUMPDEST.base_gas_cost(), 1);
+    /// assert_eq!(Mnemonic::SLOAD.base_gas_cost(), 100);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn base_gas_cost(&self) -> u64 {
+        match self.cost() {
+            Cost::Tier(tier) => tier.gas(),
+            Cost::Fixed(gas) => gas,
+        }
+    }
+
+    /// Returns the fee tier of this mnemonic as defined in the yellow paper or [`None`] if its
+    /// cost is not defined by a fee tier.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::{FeeTier, Mnemonic};
+    /// assert_eq!(Mnemonic::ADD.fee_tier(), Some(FeeTier::VeryLow));
+    /// assert_eq!(Mnemonic::MUL.fee_tier(), Some(FeeTier::Low));
+    /// assert_eq!(Mnemonic::SLOAD.fee_tier(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn fee_tier(&self) -> Option<FeeTier> {
+        match self.cost() {
+            Cost::Tier(tier) => Some(tier),
+            Cost::Fixed(_) => None,
+        }
+    }
+
+    /// Returns the fee tier of this mnemonic or, for mnemonics without a fee tier, their constant
+    /// gas cost as of the latest execution upgrade.
+    #[expect(clippy::too_many_lines, reason = "every mnemonic needs to be listed")]
+    const fn cost(self) -> Cost {
+        let tier = match self {
+            Self::STOP | Self::RETURN | Self::REVERT => FeeTier::Zero,
+            Self::ADDRESS
+            | Self::ORIGIN
+            | Self::CALLER
+            | Self::CALLVALUE
+            | Self::CALLDATASIZE
+            | Self::CODESIZE
+            | Self::GASPRICE
+            | Self::RETURNDATASIZE
+            | Self::COINBASE
+            | Self::TIMESTAMP
+            | Self::NUMBER
+            | Self::PREVRANDAO
+            | Self::GASLIMIT
+            | Self::CHAINID
+            | Self::BASEFEE
+            | Self::BLOBBASEFEE
+            | Self::POP
+            | Self::PC
+            | Self::MSIZE
+            | Self::GAS
+            | Self::PUSH0 => FeeTier::Base,
+            Self::ADD
+            | Self::SUB
+            | Self::LT
+            | Self::GT
+            | Self::SLT
+            | Self::SGT
+            | Self::EQ
+            | Self::ISZERO
+            | Self::AND
+            | Self::OR
+            | Self::XOR
+            | Self::NOT
+            | Self::BYTE
+            | Self::SHL
+            | Self::SHR
+            | Self::SAR
+            | Self::CALLDATALOAD
+            | Self::CALLDATACOPY
+            | Self::CODECOPY
+            | Self::RETURNDATACOPY
+            | Self::BLOBHASH
+            | Self::MLOAD
+            | Self::MSTORE
+            | Self::MSTORE8
+            | Self::MCOPY
+            | Self::PUSH1
+            | Self::PUSH2
+            | Self::PUSH3
+            | Self::PUSH4
+            | Self::PUSH5
+            | Self::PUSH6
+            | Self::PUSH7
+            | Self::PUSH8
+            | Self::PUSH9
+            | Self::PUSH10
+            | Self::PUSH11
+            | Self::PUSH12
+            | Self::PUSH13
+            | Self::PUSH14
+            | Self::PUSH15
+            | Self::PUSH16
+            | Self::PUSH17
+            | Self::PUSH18
+            | Self::PUSH19
+            | Self::PUSH20
+            | Self::PUSH21
+            | Self::PUSH22
+            | Self::PUSH23
+            | Self::PUSH24
+            | Self::PUSH25
+            | Self::PUSH26
+            | Self::PUSH27
+            | Self::PUSH28
+            | Self::PUSH29
+            | Self::PUSH30
+            | Self::PUSH31
+            | Self::PUSH32
+            | Self::DUP1
+            | Self::DUP2
+            | Self::DUP3
+            | Self::DUP4
+            | Self::DUP5
+            | Self::DUP6
+            | Self::DUP7
+            | Self::DUP8
+            | Self::DUP9
+            | Self::DUP10
+            | Self::DUP11
+            | Self::DUP12
+            | Self::DUP13
+            | Self::DUP14
+            | Self::DUP15
+            | Self::DUP16
+            | Self::SWAP1
+            | Self::SWAP2
+            | Self::SWAP3
+            | Self::SWAP4
+            | Self::SWAP5
+            | Self::SWAP6
+            | Self::SWAP7
+            | Self::SWAP8
+            | Self::SWAP9
+            | Self::SWAP10
+            | Self::SWAP11
+            | Self::SWAP12
+            | Self::SWAP13
+            | Self::SWAP14
+            | Self::SWAP15
+            | Self::SWAP16 => FeeTier::VeryLow,
+            Self::MUL
+            | Self::DIV
+            | Self::SDIV
+            | Self::MOD
+            | Self::SMOD
+            | Self::SIGNEXTEND
+            | Self::SELFBALANCE => FeeTier::Low,
+            Self::ADDMOD | Self::MULMOD | Self::JUMP => FeeTier::Mid,
+            Self::JUMPI => FeeTier::High,
+            Self::INVALID => return Cost::Fixed(0),
+            Self::JUMPDEST => return Cost::Fixed(1),
+            Self::EXP => return Cost::Fixed(10),
+            Self::BLOCKHASH => return Cost::Fixed(20),
+            Self::KECCAK256 => return Cost::Fixed(30),
+            Self::BALANCE
+            | Self::EXTCODESIZE
+            | Self::EXTCODECOPY
+            | Self::EXTCODEHASH
+            | Self::SLOAD
+            | Self::SSTORE
+            | Self::TLOAD
+            | Self::TSTORE
+            | Self::CALL
+            | Self::CALLCODE
+            | Self::DELEGATECALL
+            | Self::STATICCALL => return Cost::Fixed(100),
+            Self::LOG0 => return Cost::Fixed(375),
+            Self::LOG1 => return Cost::Fixed(750),
+            Self::LOG2 => return Cost::Fixed(1125),
+            Self::LOG3 => return Cost::Fixed(1500),
+            Self::LOG4 => return Cost::Fixed(1875),
+            Self::SELFDESTRUCT => return Cost::Fixed(5000),
+            Self::CREATE | Self::CREATE2 => return Cost::Fixed(32_000),
+        };
+
+        Cost::Tier(tier)
+    }
+
+    /// Returns [`true`] if the gas charged for executing this mnemonic depends on the execution
+    /// state (e.g. memory expansion, account access or the size of the operands).
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::MSTORE.has_dynamic_gas(), true);
+    /// assert_eq!(Mnemonic::ADD.has_dynamic_gas(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn has_dynamic_gas(&self) -> bool {
+        matches!(
+            self,
+            Self::EXP
+                | Self::KECCAK256
+                | Self::BALANCE
+                | Self::CALLDATACOPY
+                | Self::CODECOPY
+                | Self::EXTCODESIZE
+                | Self::EXTCODECOPY
+                | Self::RETURNDATACOPY
+                | Self::EXTCODEHASH
+                | Self::MLOAD
+                | Self::MSTORE
+                | Self::MSTORE8
+                | Self::SLOAD
+                | Self::SSTORE
+                | Self::MCOPY
+                | Self::LOG0
+                | Self::LOG1
+                | Self::LOG2
+                | Self::LOG3
+                | Self::LOG4
+                | Self::CREATE
+                | Self::CALL
+                | Self::CALLCODE
+                | Self::RETURN
+                | Self::DELEGATECALL
+                | Self::CREATE2
+                | Self::STATICCALL
+                | Self::REVERT
+                | Self::INVALID
+                | Self::SELFDESTRUCT
+        )
+    }
+
+    /// Returns [`true`] if executing this mnemonic may expand memory, which makes its gas cost
+    /// include a memory expansion component.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::MSTORE.may_expand_memory(), true);
+    /// assert_eq!(Mnemonic::RETURNDATACOPY.may_expand_memory(), true);
+    /// assert_eq!(Mnemonic::SSTORE.may_expand_memory(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn may_expand_memory(&self) -> bool {
+        matches!(
+            self,
+            Self::KECCAK256
+                | Self::CALLDATACOPY
+                | Self::CODECOPY
+                | Self::EXTCODECOPY
+                | Self::RETURNDATACOPY
+                | Self::MLOAD
+                | Self::MSTORE
+                | Self::MSTORE8
+                | Self::MCOPY
+                | Self::LOG0
+                | Self::LOG1
+                | Self::LOG2
+                | Self::LOG3
+                | Self::LOG4
+                | Self::CREATE
+                | Self::CALL
+                | Self::CALLCODE
+                | Self::RETURN
+                | Self::DELEGATECALL
+                | Self::CREATE2
+                | Self::STATICCALL
+                | Self::REVERT
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_gas_costs() {
+        assert_eq!(Mnemonic::STOP.base_gas_cost(), 0);
+        assert_eq!(Mnemonic::PUSH0.base_gas_cost(), 2);
+        assert_eq!(Mnemonic::PUSH32.base_gas_cost(), 3);
+        assert_eq!(Mnemonic::MUL.base_gas_cost(), 5);
+        assert_eq!(Mnemonic::JUMPI.base_gas_cost(), 10);
+        assert_eq!(Mnemonic::LOG4.base_gas_cost(), 1875);
+        assert_eq!(Mnemonic::CREATE2.base_gas_cost(), 32_000);
+    }
+
+    #[test]
+    fn fee_tiers() {
+        assert_eq!(Mnemonic::STOP.fee_tier(), Some(FeeTier::Zero));
+        assert_eq!(Mnemonic::ADDRESS.fee_tier(), Some(FeeTier::Base));
+        assert_eq!(Mnemonic::ADD.fee_tier(), Some(FeeTier::VeryLow));
+        assert_eq!(Mnemonic::MUL.fee_tier(), Some(FeeTier::Low));
+        assert_eq!(Mnemonic::ADDMOD.fee_tier(), Some(FeeTier::Mid));
+        assert_eq!(Mnemonic::JUMPI.fee_tier(), Some(FeeTier::High));
+
+        assert_eq!(Mnemonic::JUMPDEST.fee_tier(), None);
+        assert_eq!(Mnemonic::INVALID.fee_tier(), None);
+        assert_eq!(Mnemonic::CALL.fee_tier(), None);
+    }
+
+    #[test]
+    fn fee_tiers_back_base_gas_costs() {
+        for mnemonic in Mnemonic::VARIANTS {
+            let cost = mnemonic.base_gas_cost();
+
+            if let Some(tier) = mnemonic.fee_tier() {
+                assert_eq!(cost, tier.gas(), "{mnemonic}");
+            }
+        }
+    }
+
+    #[test]
+    fn dynamic_gas_costs() {
+        assert!(Mnemonic::KECCAK256.has_dynamic_gas());
+        assert!(Mnemonic::CALL.has_dynamic_gas());
+        assert!(Mnemonic::LOG0.has_dynamic_gas());
+        assert!(!Mnemonic::TSTORE.has_dynamic_gas());
+        assert!(!Mnemonic::SWAP1.has_dynamic_gas());
+    }
+
+    #[test]
+    fn memory_expansion() {
+        assert!(Mnemonic::MLOAD.may_expand_memory());
+        assert!(Mnemonic::MCOPY.may_expand_memory());
+        assert!(Mnemonic::CALLDATACOPY.may_expand_memory());
+        assert!(Mnemonic::KECCAK256.may_expand_memory());
+        assert!(Mnemonic::CREATE2.may_expand_memory());
+        assert!(Mnemonic::STATICCALL.may_expand_memory());
+        assert!(Mnemonic::LOG4.may_expand_memory());
+        assert!(Mnemonic::REVERT.may_expand_memory());
+
+        assert!(!Mnemonic::ADD.may_expand_memory());
+        assert!(!Mnemonic::MSIZE.may_expand_memory());
+        assert!(!Mnemonic::SLOAD.may_expand_memory());
+        assert!(!Mnemonic::TSTORE.may_expand_memory());
+        assert!(!Mnemonic::SELFDESTRUCT.may_expand_memory());
+
+        for mnemonic in Mnemonic::VARIANTS {
+            if mnemonic.may_expand_memory() {
+                assert!(mnemonic.has_dynamic_gas(), "{mnemonic}");
+            }
+        }
+    }
+}
@@ -2,10 +2,13 @@
 
 mod dup;
 mod log;
+mod operand;
 mod push;
 mod swap;
 mod unknown;
 
+use thiserror::Error;
+
 use crate::{
     AssemblyInstruction, Mnemonic, OpCode,
     assembly::DisassemblyError,
@@ -15,7 +18,8 @@ use crate::{
 pub use crate::defs::instruction::*;
 pub use dup::Dup;
 pub use log::Log;
-pub use push::Push;
+pub use operand::Operand;
+pub use push::{Push, PushOverflow};
 pub use swap::Swap;
 pub use unknown::Unknown;
 
@@ -32,16 +36,146 @@ impl AssemblyInstruction for Instruction {
         match_instruction!(self, AssemblyInstruction::immediate_size)
     }
 
+    fn assemble(self) -> Vec<u8> {
+        match_instruction!(self, AssemblyInstruction::assemble)
+    }
+
+    fn operand(&self) -> Operand<'_> {
+        match_instruction!(self, AssemblyInstruction::operand)
+    }
+
     fn disassemble(bytes: &[u8]) -> Result<Self, DisassemblyError> {
         disassemble_instruction!(bytes)
     }
 }
 
+impl TryFrom<&[u8]> for Instruction {
+    type Error = DisassemblyError;
+
+    /// Disassembles an instruction from a sequence of bytes, see
+    /// [`AssemblyInstruction::disassemble`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::{instruction::{Instruction, Push}, DisassemblyError};
+    /// let bytes: &[u8] = &[0x60, 0x01];
+    /// assert_eq!(Instruction::try_from(bytes).unwrap(), Instruction::Push1(Push::new([0x01])));
+    ///
+    /// let empty: &[u8] = &[];
+    /// let result: Result<Instruction, _> = empty.try_into();
+    /// assert!(matches!(result, Err(DisassemblyError::UnexpectedLength { got: 0, expected: 1 })));
+    /// ```
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::disassemble(bytes)
+    }
+}
+
+/// An error that happens when an instruction can not be built from a mnemonic alone, because it
+/// needs an immediate value (`PUSH1` to `PUSH32`).
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("`{mnemonic}` requires an immediate value")]
+pub struct MissingImmediate {
+    /// The mnemonic that requires an immediate value.
+    pub mnemonic: Mnemonic,
+}
+
+impl TryFrom<Mnemonic> for Instruction {
+    type Error = MissingImmediate;
+
+    /// Builds the instruction for a mnemonic without an immediate value.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::{instruction::{Add, Dup, Instruction}, Mnemonic};
+    /// assert_eq!(Instruction::try_from(Mnemonic::ADD), Ok(Instruction::Add(Add)));
+    /// assert_eq!(Instruction::try_from(Mnemonic::DUP2), Ok(Instruction::Dup2(Dup::new())));
+    /// assert!(Instruction::try_from(Mnemonic::PUSH1).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error for `PUSH1` to `PUSH32`, which need an immediate value.
+    fn try_from(mnemonic: Mnemonic) -> Result<Self, Self::Error> {
+        Self::disassemble(&[mnemonic.into_byte()]).map_err(|_| MissingImmediate { mnemonic })
+    }
+}
+
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.opcode())?;
+
+        match self.operand() {
+            Operand::None => Ok(()),
+            operand @ Operand::Immediate(_) => write!(f, " {operand}"),
+        }
+    }
+}
+
+impl PartialEq<Mnemonic> for Instruction {
+    #[inline]
+    fn eq(&self, other: &Mnemonic) -> bool {
+        self.mnemonic() == Some(*other)
+    }
+}
+
+impl PartialEq<Instruction> for Mnemonic {
+    #[inline]
+    fn eq(&self, other: &Instruction) -> bool {
+        other.eq(self)
+    }
+}
+
+impl PartialEq<u8> for Instruction {
+    #[inline]
+    fn eq(&self, other: &u8) -> bool {
+        self.opcode().into_byte() == *other
+    }
+}
+
+impl PartialEq<Instruction> for u8 {
+    #[inline]
+    fn eq(&self, other: &Instruction) -> bool {
+        other.eq(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::Mnemonic;
 
+    #[test]
+    fn instruction_from_mnemonic() {
+        assert_eq!(
+            Instruction::try_from(Mnemonic::ADD),
+            Ok(Instruction::Add(Add))
+        );
+        assert_eq!(
+            Instruction::try_from(Mnemonic::PUSH0),
+            Ok(Instruction::Push0(Push::new([])))
+        );
+        assert_eq!(
+            Instruction::try_from(Mnemonic::PUSH1),
+            Err(MissingImmediate {
+                mnemonic: Mnemonic::PUSH1
+            })
+        );
+        assert!(Instruction::try_from(Mnemonic::PUSH32).is_err());
+
+        for &mnemonic in Mnemonic::VARIANTS {
+            match Instruction::try_from(mnemonic) {
+                Ok(instruction) => {
+                    assert_eq!(instruction, mnemonic);
+                    assert_eq!(instruction.immediate_size(), 0);
+                }
+                Err(error) => {
+                    assert_eq!(error.mnemonic, mnemonic);
+                    assert!(mnemonic.push_size().is_some_and(|size| size > 0));
+                }
+            }
+        }
+    }
+
     #[test]
     fn instruction_fmt_is_sane() {
         assert_eq!(format!("{Gas:?}"), "Gas");
@@ -52,6 +186,80 @@ mod tests {
         assert_eq!(format!("{Gas:o}"), "132");
     }
 
+    #[test]
+    fn instruction_display_includes_operands() {
+        assert_eq!(Instruction::Gas(Gas).to_string(), "GAS");
+        assert_eq!(Instruction::Push0(Push::new([])).to_string(), "PUSH0");
+        assert_eq!(
+            Instruction::Push2(Push::new([0x0A, 0x0B])).to_string(),
+            "PUSH2 0x0a0b"
+        );
+        assert_eq!(
+            Instruction::Push32(Push::new([0xFF; 32])).to_string(),
+            format!("PUSH32 0x{}", "ff".repeat(32))
+        );
+        assert_eq!(Instruction::Dup3(Dup::new()).to_string(), "DUP3");
+        assert_eq!(Instruction::Swap16(Swap::new()).to_string(), "SWAP16");
+        assert_eq!(Instruction::Log0(Log::new()).to_string(), "LOG0");
+        assert_eq!(
+            Instruction::Unknown(Unknown::new(0xF)).to_string(),
+            "UNKNOWN"
+        );
+    }
+
+    #[test]
+    fn instruction_assembles_immediates() {
+        assert_eq!(Instruction::Gas(Gas).assemble(), vec![0x5A]);
+        assert_eq!(
+            Instruction::Push2(Push::new([0x0A, 0x0B])).assemble(),
+            vec![0x61, 0x0A, 0x0B]
+        );
+        assert_eq!(
+            Instruction::Unknown(Unknown::new(0xF)).assemble(),
+            vec![0xF]
+        );
+    }
+
+    #[test]
+    fn instruction_operand() {
+        assert_eq!(Instruction::Gas(Gas).operand(), Operand::None);
+        assert_eq!(Instruction::Push0(Push::new([])).operand(), Operand::None);
+        assert_eq!(
+            Instruction::Push2(Push::new([0x0A, 0x0B])).operand(),
+            Operand::Immediate(&[0x0A, 0x0B])
+        );
+        assert_eq!(
+            Instruction::Unknown(Unknown::new(0xF)).operand(),
+            Operand::None
+        );
+    }
+
+    #[test]
+    fn instruction_mnemonic_eq() {
+        assert_eq!(Instruction::Add(Add), Mnemonic::ADD);
+        assert_eq!(Mnemonic::ADD, Instruction::Add(Add));
+        assert_eq!(Instruction::Push1(Push::new([0x1])), Mnemonic::PUSH1);
+
+        assert_ne!(Instruction::Add(Add), Mnemonic::GAS);
+        assert_ne!(Mnemonic::GAS, Instruction::Add(Add));
+
+        for mnemonic in Mnemonic::VARIANTS {
+            assert_ne!(Instruction::Unknown(Unknown::new(0xF)), *mnemonic);
+            assert_ne!(*mnemonic, Instruction::Unknown(Unknown::new(0xF)));
+        }
+    }
+
+    #[test]
+    fn instruction_byte_eq() {
+        assert_eq!(Instruction::Gas(Gas), 0x5A);
+        assert_eq!(0x5A, Instruction::Gas(Gas));
+        assert_eq!(Instruction::Push1(Push::new([0x01])), 0x60);
+        assert_eq!(Instruction::Unknown(Unknown::new(0xF)), 0xF);
+
+        assert_ne!(Instruction::Gas(Gas), 0x5B);
+        assert_ne!(Instruction::Push1(Push::new([0x01])), 0x01);
+    }
+
     #[test]
     fn it_disassembles_instructions() {
         assert_eq!(
@@ -0,0 +1,55 @@
+//! Instruction operands.
+
+use std::fmt::{self, Display};
+
+/// The operand of an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand<'a> {
+    /// The instruction has no operand.
+    None,
+    /// The instruction has an immediate value that follows its opcode in the bytecode.
+    Immediate(&'a [u8]),
+}
+
+impl Operand<'_> {
+    /// Returns [`true`] if there is no operand.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::instruction::Operand;
+    /// assert!(Operand::None.is_none());
+    /// assert!(!Operand::Immediate(&[0x01]).is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+impl Display for Operand<'_> {
+    /// Formats an immediate as a lowercase hex string prefixed with `0x`. Formats nothing if there
+    /// is no operand.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Self::Immediate(bytes) = self {
+            write!(f, "0x")?;
+
+            for byte in *bytes {
+                write!(f, "{byte:02x}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operand_fmt() {
+        assert_eq!(Operand::None.to_string(), "");
+        assert_eq!(Operand::Immediate(&[0x0A, 0xFF]).to_string(), "0x0aff");
+    }
+}
@@ -1,6 +1,20 @@
 //! The `PUSHx` instruction.
 
-use crate::{AssemblyInstruction, Mnemonic, OpCode, assembly::DisassemblyError};
+use thiserror::Error;
+
+use crate::{
+    AssemblyInstruction, Mnemonic, OpCode, assembly::DisassemblyError, instruction::Operand,
+};
+
+/// An error that happens when a value does not fit into the immediate of a `PUSHx` instruction.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("value `{value:#x}` does not fit into a {size} byte immediate")]
+pub struct PushOverflow {
+    /// The value that was pushed.
+    pub value: u128,
+    /// The size of the immediate value in bytes.
+    pub size: usize,
+}
 
 /// Place `N`-byte item on stack.
 /// The `N` constant signifies the type of the `PUSH` opcode (e.g. `Push<32>` => `PUSH32`).
@@ -40,6 +54,33 @@ impl<const N: usize> Push<N> {
         Self { immediate }
     }
 
+    /// Create a new `PUSH` instruction from a value, encoded as a big-endian immediate.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::instruction::Push;
+    /// assert_eq!(Push::<2>::from_value(0x1234), Ok(Push::new([0x12, 0x34])));
+    /// assert_eq!(Push::<4>::from_value(0x1234), Ok(Push::new([0, 0, 0x12, 0x34])));
+    /// assert!(Push::<1>::from_value(0x1234).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the value needs more than `N` bytes.
+    pub fn from_value(value: u128) -> Result<Self, PushOverflow> {
+        let bytes = value.to_be_bytes();
+        let significant = bytes.len() - bytes.iter().take_while(|&&byte| byte == 0).count();
+
+        if significant > N {
+            return Err(PushOverflow { value, size: N });
+        }
+
+        let mut immediate = [0; N];
+        let copied = N.min(bytes.len());
+        immediate[N - copied..].copy_from_slice(&bytes[bytes.len() - copied..]);
+
+        Ok(Self::new(immediate))
+    }
+
     /// Get a reference to the immediate value.
     ///
     /// # Example
@@ -54,6 +95,28 @@ impl<const N: usize> Push<N> {
         &self.immediate
     }
 
+    /// Returns the immediate value as a 32-byte big-endian word, zero-extended on the left.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::instruction::Push;
+    /// let word = Push::new([0x01, 0x02]).to_u256();
+    /// assert_eq!(word[..30], [0; 30]);
+    /// assert_eq!(word[30..], [0x01, 0x02]);
+    /// ```
+    #[must_use]
+    pub const fn to_u256(&self) -> [u8; 32] {
+        let mut word = [0; 32];
+        let mut i = 0;
+
+        while i < N {
+            word[32 - N + i] = self.immediate[i];
+            i += 1;
+        }
+
+        word
+    }
+
     /// Return the size of the immediate value.
     ///
     /// # Example
@@ -149,6 +212,16 @@ impl<const N: usize> AssemblyInstruction for Push<N> {
         N as u8
     }
 
+    #[inline]
+    fn operand(&self) -> Operand<'_> {
+        // `PUSH0` has no immediate value.
+        if N == 0 {
+            Operand::None
+        } else {
+            Operand::Immediate(self.immediate())
+        }
+    }
+
     fn assemble(self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(N + 1);
 
@@ -203,16 +276,62 @@ macro_rules! impl_push_fmt {
     };
 }
 
-impl_push_fmt!(Display, "{} 0x", "{:02X}");
 impl_push_fmt!(LowerHex, "{:02x}", "{:02x}");
 impl_push_fmt!(UpperHex, "{:02X}", "{:02X}");
 impl_push_fmt!(Binary, "{:08b}", "{:08b}");
 // octal?
 
+impl<const N: usize> std::fmt::Display for Push<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.opcode())?;
+
+        match self.operand() {
+            Operand::None => Ok(()),
+            operand @ Operand::Immediate(_) => write!(f, " {operand}"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn push_from_value() {
+        assert_eq!(Push::<1>::from_value(0xFF), Ok(Push::new([0xFF])));
+        assert_eq!(Push::<16>::from_value(u128::MAX), Ok(Push::new([0xFF; 16])));
+
+        assert_eq!(
+            Push::<1>::from_value(0x1234),
+            Err(PushOverflow {
+                value: 0x1234,
+                size: 1
+            })
+        );
+        assert_eq!(
+            Push::<1>::from_value(0x1234).unwrap_err().to_string(),
+            "value `0x1234` does not fit into a 1 byte immediate"
+        );
+
+        assert_eq!(Push::<0>::from_value(0), Ok(Push::new([])));
+        assert_eq!(Push::<3>::from_value(0), Ok(Push::new([0; 3])));
+        assert!(Push::<0>::from_value(1).is_err());
+
+        let mut word = [0; 32];
+        word[31] = 0x07;
+        assert_eq!(Push::<32>::from_value(7), Ok(Push::new(word)));
+    }
+
+    #[test]
+    fn push_to_u256() {
+        let mut one = [0; 32];
+        one[31] = 0x01;
+
+        assert_eq!(Push::new([0x01]).to_u256(), one);
+        assert_eq!(Push::new([0xFF; 32]).to_u256(), [0xFF; 32]);
+        assert_eq!(Push::new([]).to_u256(), [0; 32]);
+    }
+
     #[test]
     fn push_is_sane() {
         let push = Push::new([1, 2, 3, 4]);
@@ -225,7 +344,8 @@ mod tests {
     fn push_fmt_is_sane() {
         let push = Push::new([0xA, 0xB, 0xC]);
         assert_eq!(format!("{push:?}"), "Push { immediate: [10, 11, 12] }");
-        assert_eq!(format!("{push}"), "PUSH3 0x0A0B0C");
+        assert_eq!(format!("{push}"), "PUSH3 0x0a0b0c");
+        assert_eq!(format!("{}", Push::new([])), "PUSH0");
         assert_eq!(format!("{push:x}"), "620a0b0c");
         assert_eq!(format!("{push:X}"), "620A0B0C");
         let push = Push::new([0b10, 0b11]);
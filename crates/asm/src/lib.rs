@@ -4,12 +4,22 @@
 
 mod assembly;
 mod defs;
+#[cfg(feature = "descriptions")]
+mod description;
 mod fmt;
+mod gas;
 pub mod instruction;
 mod mnemonic;
 mod opcode;
+pub mod prelude;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod text;
 
-pub use assembly::AssemblyInstruction;
+pub use assembly::{AssemblyInstruction, DisassemblyError};
+pub use gas::FeeTier;
 pub use instruction::Instruction;
 pub use mnemonic::Mnemonic;
-pub use opcode::OpCode;
+pub use opcode::{OPCODE_TABLE, OpCode};
@@ -1,11 +1,12 @@
 //! EVM mnemonics.
 
 pub use crate::defs::mnemonic::Mnemonic;
-use crate::fmt::forward_byte_fmt;
+use crate::{fmt::forward_byte_fmt, opcode::OPCODE_TABLE};
 use std::cmp::Ordering;
 
 impl Mnemonic {
-    /// Converts mnemonic into its byte representation.
+    /// Converts mnemonic into its byte representation. The enum discriminants are the opcode
+    /// bytes, so this is a plain cast that needs no lookup table.
     ///
     /// # Examples
     /// ```
@@ -18,6 +19,22 @@ impl Mnemonic {
         self as u8
     }
 
+    /// Returns an iterator over all mnemonics in ascending opcode byte order, independent of the
+    /// declaration order of [`Mnemonic::VARIANTS`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// let mut mnemonics = Mnemonic::iter_by_opcode();
+    /// assert_eq!(mnemonics.next(), Some(Mnemonic::STOP));
+    /// assert_eq!(mnemonics.next(), Some(Mnemonic::ADD));
+    /// assert_eq!(mnemonics.last(), Some(Mnemonic::SELFDESTRUCT));
+    /// ```
+    #[inline]
+    pub fn iter_by_opcode() -> impl Iterator<Item = Self> {
+        OPCODE_TABLE.iter().copied().flatten()
+    }
+
     /// Returns [`true`] if this mnemonic is of the type `PUSHx`.
     ///
     /// # Example
@@ -67,6 +84,63 @@ impl Mnemonic {
         )
     }
 
+    /// Returns [`true`] if this mnemonic is `PUSH0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::PUSH0.is_push0(), true);
+    /// assert_eq!(Mnemonic::PUSH1.is_push0(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_push0(&self) -> bool {
+        matches!(self, Self::PUSH0)
+    }
+
+    /// Returns the size of the immediate value of a `PUSHx` mnemonic or [`None`] if this mnemonic
+    /// is not a `PUSHx`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::PUSH0.push_size(), Some(0));
+    /// assert_eq!(Mnemonic::PUSH32.push_size(), Some(32));
+    /// assert_eq!(Mnemonic::GAS.push_size(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn push_size(&self) -> Option<u8> {
+        if self.is_push() {
+            Some(self.into_byte() - Self::PUSH0.into_byte())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest `PUSHx` mnemonic whose immediate value can hold `byte_len` bytes or
+    /// [`None`] if no `PUSHx` is large enough.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::smallest_push_for(0), Some(Mnemonic::PUSH0));
+    /// assert_eq!(Mnemonic::smallest_push_for(2), Some(Mnemonic::PUSH2));
+    /// assert_eq!(Mnemonic::smallest_push_for(33), None);
+    /// ```
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "`byte_len` is checked to be at most 32"
+    )]
+    #[must_use]
+    pub const fn smallest_push_for(byte_len: usize) -> Option<Self> {
+        if byte_len > 32 {
+            return None;
+        }
+
+        Self::from_byte(Self::PUSH0.into_byte() + byte_len as u8)
+    }
+
     /// Returns [`true`] if this mnemonic is of the type `DUPx`.
     ///
     /// # Example
@@ -179,6 +253,152 @@ impl Mnemonic {
     pub const fn is_control_flow(&self) -> bool {
         matches!(self, Self::JUMP | Self::JUMPI | Self::JUMPDEST)
     }
+
+    /// Returns [`true`] if this mnemonic is a `JUMP` or a `JUMPI`.
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::JUMPI.is_jump(), true);
+    /// assert_eq!(Mnemonic::JUMPDEST.is_jump(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_jump(&self) -> bool {
+        matches!(self, Self::JUMP | Self::JUMPI)
+    }
+
+    /// Returns [`true`] if this mnemonic is a `JUMPDEST`, the only valid target of a jump.
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::JUMPDEST.is_jumpdest(), true);
+    /// assert_eq!(Mnemonic::JUMP.is_jumpdest(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_jumpdest(&self) -> bool {
+        matches!(self, Self::JUMPDEST)
+    }
+
+    /// Returns [`true`] if this mnemonic is a message-call into another account (`CALL`,
+    /// `CALLCODE`, `DELEGATECALL` or `STATICCALL`).
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::DELEGATECALL.is_call(), true);
+    /// assert_eq!(Mnemonic::CREATE.is_call(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_call(&self) -> bool {
+        matches!(
+            self,
+            Self::CALL | Self::CALLCODE | Self::DELEGATECALL | Self::STATICCALL
+        )
+    }
+
+    /// Returns [`true`] if this mnemonic creates a new contract (`CREATE` or `CREATE2`).
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::CREATE2.creates_contract(), true);
+    /// assert_eq!(Mnemonic::CALL.creates_contract(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn creates_contract(&self) -> bool {
+        matches!(self, Self::CREATE | Self::CREATE2)
+    }
+
+    /// Returns [`true`] if this mnemonic may modify the state.
+    ///
+    /// # Note
+    /// `CALL` is considered state modifying because it can transfer value, even though a call
+    /// without value does not modify the state.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::SSTORE.is_state_modifying(), true);
+    /// assert_eq!(Mnemonic::LOG2.is_state_modifying(), true);
+    /// assert_eq!(Mnemonic::SLOAD.is_state_modifying(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_state_modifying(&self) -> bool {
+        matches!(
+            self,
+            Self::SSTORE
+                | Self::TSTORE
+                | Self::LOG0
+                | Self::LOG1
+                | Self::LOG2
+                | Self::LOG3
+                | Self::LOG4
+                | Self::CREATE
+                | Self::CREATE2
+                | Self::SELFDESTRUCT
+                | Self::CALL
+        )
+    }
+
+    /// Returns [`true`] if this mnemonic can be executed inside a static context (a `STATICCALL`
+    /// frame) without reverting.
+    ///
+    /// # Note
+    /// `CALL` is considered valid because it only reverts in a static context when it transfers a
+    /// non-zero value, which can not be determined from the mnemonic alone.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::TSTORE.is_valid_in_static_context(), false);
+    /// assert_eq!(Mnemonic::TLOAD.is_valid_in_static_context(), true);
+    /// assert_eq!(Mnemonic::CALL.is_valid_in_static_context(), true);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_valid_in_static_context(&self) -> bool {
+        matches!(self, Self::CALL) || !self.is_state_modifying()
+    }
+
+    /// Returns the number of the EIP that introduced this mnemonic or [`None`] if the mnemonic
+    /// was available since Frontier.
+    ///
+    /// # Note
+    /// `PREVRANDAO` returns [`None`], since EIP-4399 only changed the semantics of the existing
+    /// `DIFFICULTY` opcode.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::Mnemonic;
+    /// assert_eq!(Mnemonic::PUSH0.origin_eip(), Some(3855));
+    /// assert_eq!(Mnemonic::TSTORE.origin_eip(), Some(1153));
+    /// assert_eq!(Mnemonic::ADD.origin_eip(), None);
+    /// ```
+    #[must_use]
+    pub const fn origin_eip(&self) -> Option<u32> {
+        let eip = match self {
+            Self::DELEGATECALL => 7,
+            Self::REVERT => 140,
+            Self::SHL | Self::SHR | Self::SAR => 145,
+            Self::RETURNDATASIZE | Self::RETURNDATACOPY => 211,
+            Self::STATICCALL => 214,
+            Self::CREATE2 => 1014,
+            Self::EXTCODEHASH => 1052,
+            Self::TLOAD | Self::TSTORE => 1153,
+            Self::CHAINID => 1344,
+            Self::SELFBALANCE => 1884,
+            Self::BASEFEE => 3198,
+            Self::PUSH0 => 3855,
+            Self::BLOBHASH => 4844,
+            Self::MCOPY => 5656,
+            Self::BLOBBASEFEE => 7516,
+            _ => return None,
+        };
+
+        Some(eip)
+    }
 }
 
 impl From<Mnemonic> for u8 {
@@ -223,6 +443,49 @@ mod tests {
     use super::*;
     use Mnemonic::{GAS, STOP};
 
+    #[test]
+    fn mnemonic_iter_by_opcode() {
+        let mnemonics: Vec<_> = Mnemonic::iter_by_opcode().collect();
+
+        assert_eq!(mnemonics.len(), Mnemonic::COUNT);
+        assert!(
+            mnemonics
+                .windows(2)
+                .all(|pair| pair[0].into_byte() < pair[1].into_byte())
+        );
+        assert!(
+            Mnemonic::VARIANTS
+                .iter()
+                .all(|mnemonic| mnemonics.contains(mnemonic))
+        );
+    }
+
+    #[test]
+    fn mnemonic_bytes() {
+        for mnemonic in Mnemonic::VARIANTS {
+            let byte = mnemonic.into_byte();
+            assert_eq!(Mnemonic::from_byte(byte), Some(*mnemonic));
+            assert_eq!(OPCODE_TABLE[usize::from(byte)], Some(*mnemonic));
+        }
+    }
+
+    #[test]
+    fn mnemonic_jumps() {
+        let jumps: Vec<_> = Mnemonic::VARIANTS.iter().filter(|m| m.is_jump()).collect();
+        let jumpdests: Vec<_> = Mnemonic::VARIANTS
+            .iter()
+            .filter(|m| m.is_jumpdest())
+            .collect();
+
+        assert_eq!(jumps, [&Mnemonic::JUMP, &Mnemonic::JUMPI]);
+        assert_eq!(jumpdests, [&Mnemonic::JUMPDEST]);
+        assert!(
+            Mnemonic::VARIANTS
+                .iter()
+                .all(|m| m.is_control_flow() == (m.is_jump() || m.is_jumpdest()))
+        );
+    }
+
     #[test]
     fn mnemonic_format() {
         assert_eq!(GAS.to_string(), "GAS");
@@ -260,6 +523,88 @@ mod tests {
         assert!(GAS > STOP);
     }
 
+    #[test]
+    fn mnemonic_push_size() {
+        let pushes: Vec<_> = Mnemonic::VARIANTS
+            .iter()
+            .filter_map(|mnemonic| mnemonic.push_size().map(|size| (*mnemonic, size)))
+            .collect();
+
+        assert_eq!(pushes.len(), 33);
+
+        for (mnemonic, size) in pushes {
+            assert_eq!(mnemonic.to_string(), format!("PUSH{size}"));
+            assert_eq!(mnemonic.is_push0(), size == 0);
+        }
+
+        assert_eq!(Mnemonic::DUP1.push_size(), None);
+        assert!(!Mnemonic::STOP.is_push0());
+    }
+
+    #[test]
+    fn mnemonic_smallest_push_for() {
+        assert_eq!(Mnemonic::smallest_push_for(0), Some(Mnemonic::PUSH0));
+        assert_eq!(Mnemonic::smallest_push_for(1), Some(Mnemonic::PUSH1));
+        assert_eq!(Mnemonic::smallest_push_for(20), Some(Mnemonic::PUSH20));
+        assert_eq!(Mnemonic::smallest_push_for(32), Some(Mnemonic::PUSH32));
+        assert_eq!(Mnemonic::smallest_push_for(33), None);
+    }
+
+    #[test]
+    fn mnemonic_call_family() {
+        let calls = [
+            Mnemonic::CALL,
+            Mnemonic::CALLCODE,
+            Mnemonic::DELEGATECALL,
+            Mnemonic::STATICCALL,
+        ];
+        let creates = [Mnemonic::CREATE, Mnemonic::CREATE2];
+
+        for mnemonic in Mnemonic::VARIANTS {
+            assert_eq!(mnemonic.is_call(), calls.contains(mnemonic));
+            assert_eq!(mnemonic.creates_contract(), creates.contains(mnemonic));
+        }
+    }
+
+    #[test]
+    fn mnemonic_origin_eip() {
+        assert_eq!(Mnemonic::DELEGATECALL.origin_eip(), Some(7));
+        assert_eq!(Mnemonic::SAR.origin_eip(), Some(145));
+        assert_eq!(Mnemonic::SELFBALANCE.origin_eip(), Some(1884));
+        assert_eq!(Mnemonic::BLOBHASH.origin_eip(), Some(4844));
+        assert_eq!(Mnemonic::PREVRANDAO.origin_eip(), None);
+        assert_eq!(Mnemonic::CALLCODE.origin_eip(), None);
+    }
+
+    #[test]
+    fn mnemonic_static_context() {
+        for mnemonic in [
+            Mnemonic::SSTORE,
+            Mnemonic::TSTORE,
+            Mnemonic::LOG0,
+            Mnemonic::LOG4,
+            Mnemonic::CREATE,
+            Mnemonic::CREATE2,
+            Mnemonic::SELFDESTRUCT,
+        ] {
+            assert!(mnemonic.is_state_modifying());
+            assert!(!mnemonic.is_valid_in_static_context());
+        }
+
+        assert!(Mnemonic::CALL.is_state_modifying());
+        assert!(Mnemonic::CALL.is_valid_in_static_context());
+
+        for mnemonic in [
+            Mnemonic::SLOAD,
+            Mnemonic::TLOAD,
+            Mnemonic::STATICCALL,
+            Mnemonic::DELEGATECALL,
+        ] {
+            assert!(!mnemonic.is_state_modifying());
+            assert!(mnemonic.is_valid_in_static_context());
+        }
+    }
+
     #[test]
     fn mnemonic_byte_conversions() {
         assert_eq!(GAS.into_byte(), 0x5A);
@@ -3,8 +3,29 @@
 use crate::{defs::mnemonic::Mnemonic, fmt::forward_byte_fmt};
 use std::fmt::Display;
 
+/// Lookup table of the known [`Mnemonic`] of every byte, built at compile time.
+///
+/// # Example
+/// ```
+/// # use oculars_asm::{Mnemonic, OPCODE_TABLE};
+/// assert_eq!(OPCODE_TABLE[0x5A], Some(Mnemonic::GAS));
+/// assert_eq!(OPCODE_TABLE[0x0F], None);
+/// ```
+pub const OPCODE_TABLE: [Option<Mnemonic>; 256] = {
+    let mut table = [None; 256];
+    let mut i = 0;
+
+    while i < Mnemonic::COUNT {
+        let mnemonic = Mnemonic::VARIANTS[i];
+        table[mnemonic.into_byte() as usize] = Some(mnemonic);
+        i += 1;
+    }
+
+    table
+};
+
 /// EVM operation code.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub enum OpCode {
     /// An opcode with a known [`Mnemonic`].
     Known(Mnemonic),
@@ -53,7 +74,7 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn from_byte(byte: u8) -> Self {
-        match Mnemonic::from_byte(byte) {
+        match OPCODE_TABLE[byte as usize] {
             Some(mnemonic) => Self::Known(mnemonic),
             None => Self::Unknown(byte),
         }
@@ -71,13 +92,27 @@ impl OpCode {
     #[must_use]
     #[inline]
     pub const fn try_from_byte(byte: u8) -> Option<Self> {
-        if let Some(mnemonic) = Mnemonic::from_byte(byte) {
+        if let Some(mnemonic) = OPCODE_TABLE[byte as usize] {
             Some(Self::Known(mnemonic))
         } else {
             None
         }
     }
 
+    /// Returns an iterator over all 256 opcodes in byte order, including unknown ones.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::{Mnemonic, OpCode};
+    /// let mut opcodes = OpCode::iter_all();
+    /// assert_eq!(opcodes.next(), Some(OpCode::Known(Mnemonic::STOP)));
+    /// assert_eq!(opcodes.last(), Some(OpCode::Known(Mnemonic::SELFDESTRUCT)));
+    /// ```
+    #[inline]
+    pub fn iter_all() -> impl Iterator<Item = Self> {
+        (0..=u8::MAX).map(Self::from_byte)
+    }
+
     /// Converts this opcode into a byte.
     ///
     /// # Example
@@ -112,6 +147,24 @@ impl OpCode {
         }
     }
 
+    /// Returns the size of the immediate value of a `PUSHx` opcode or [`None`] if this opcode is
+    /// not a `PUSHx`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::{Mnemonic, OpCode};
+    /// assert_eq!(OpCode::Known(Mnemonic::PUSH7).push_size(), Some(7));
+    /// assert_eq!(OpCode::Unknown(0xF).push_size(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn push_size(&self) -> Option<u8> {
+        match self {
+            OpCode::Known(mnemonic) => mnemonic.push_size(),
+            OpCode::Unknown(_) => None,
+        }
+    }
+
     /// Returns [`true`] if this opcode is of the type `DUPx`.
     ///
     /// # Example
@@ -181,6 +234,21 @@ impl OpCode {
         }
     }
 
+    /// Returns [`true`] if this opcode is the designated `INVALID` instruction (`0xFE`), as opposed
+    /// to a byte that is not a known opcode. Both halt execution, see [`OpCode::is_terminator`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_asm::{Mnemonic, OpCode};
+    /// assert_eq!(OpCode::from_byte(0xFE).is_designated_invalid(), true);
+    /// assert_eq!(OpCode::from_byte(0x0F).is_designated_invalid(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_designated_invalid(&self) -> bool {
+        matches!(self, OpCode::Known(Mnemonic::INVALID))
+    }
+
     /// Returns [`true`] if this mnemonic is a `JUMP`, `JUMPI` or a `JUMPDEST`.
     /// # Example
     /// ```
@@ -244,6 +312,21 @@ impl PartialEq<OpCode> for u8 {
     }
 }
 
+impl Ord for OpCode {
+    /// Compares opcodes by their byte value. An unknown opcode with the same byte as a known one
+    /// (which can only be constructed manually) is ordered first, so that only equal opcodes
+    /// compare as [`Equal`](std::cmp::Ordering::Equal).
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.into_byte(), self.is_known()).cmp(&(other.into_byte(), other.is_known()))
+    }
+}
+
+impl PartialOrd for OpCode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl PartialOrd<u8> for OpCode {
     fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
         self.into_byte().partial_cmp(other)
@@ -296,6 +379,57 @@ impl PartialOrd<OpCode> for Mnemonic {
 mod tests {
     use super::*;
 
+    #[test]
+    fn opcode_table_matches_from_byte() {
+        for byte in 0..=u8::MAX {
+            assert_eq!(OPCODE_TABLE[usize::from(byte)], Mnemonic::from_byte(byte));
+        }
+
+        assert_eq!(
+            OPCODE_TABLE.iter().flatten().count(),
+            Mnemonic::VARIANTS.len()
+        );
+    }
+
+    #[test]
+    fn designated_invalid_and_unknown_opcodes() {
+        let invalid = OpCode::from_byte(0xFE);
+        let unknown = OpCode::from_byte(0x0F);
+
+        assert!(invalid.is_designated_invalid());
+        assert!(invalid.is_known());
+        assert!(invalid.is_terminator());
+
+        assert!(!unknown.is_designated_invalid());
+        assert!(unknown.is_unknown());
+        assert!(unknown.is_terminator());
+    }
+
+    #[test]
+    fn opcode_ord_by_byte() {
+        let mut opcodes = vec![
+            OpCode::Known(Mnemonic::SELFDESTRUCT),
+            OpCode::Unknown(0x0D),
+            OpCode::Known(Mnemonic::ADD),
+            OpCode::Unknown(0xEF),
+            OpCode::Known(Mnemonic::STOP),
+            OpCode::Unknown(0x0C),
+        ];
+        opcodes.sort();
+
+        assert_eq!(
+            opcodes,
+            vec![
+                OpCode::Known(Mnemonic::STOP),
+                OpCode::Known(Mnemonic::ADD),
+                OpCode::Unknown(0x0C),
+                OpCode::Unknown(0x0D),
+                OpCode::Unknown(0xEF),
+                OpCode::Known(Mnemonic::SELFDESTRUCT),
+            ]
+        );
+    }
+
     #[test]
     fn opcode_format() {
         let gas = OpCode::Known(Mnemonic::GAS);
@@ -358,6 +492,42 @@ mod tests {
         assert_eq!(unk.partial_cmp(&Mnemonic::STOP), None);
     }
 
+    #[test]
+    fn opcode_ord_is_consistent_with_eq() {
+        use std::cmp::Ordering;
+
+        let opcodes = [
+            OpCode::Unknown(0x01),
+            OpCode::Known(Mnemonic::ADD),
+            OpCode::Unknown(0x0F),
+            OpCode::Known(Mnemonic::GAS),
+            OpCode::Unknown(0x5A),
+        ];
+
+        for a in opcodes {
+            for b in opcodes {
+                assert_eq!(a.cmp(&b) == Ordering::Equal, a == b, "{a:?} {b:?}");
+                assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            }
+        }
+
+        assert!(OpCode::Unknown(0x01) < OpCode::Known(Mnemonic::ADD));
+        assert!(OpCode::Known(Mnemonic::ADD) < OpCode::Unknown(0x0F));
+    }
+
+    #[test]
+    fn opcode_iter_all() {
+        let opcodes: Vec<OpCode> = OpCode::iter_all().collect();
+
+        assert_eq!(opcodes.len(), 256);
+        assert_eq!(opcodes[0x5A], OpCode::Known(Mnemonic::GAS));
+        assert_eq!(opcodes[0xF], OpCode::Unknown(0xF));
+
+        for (byte, opcode) in opcodes.into_iter().enumerate() {
+            assert_eq!(usize::from(opcode.into_byte()), byte);
+        }
+    }
+
     #[test]
     fn opcode_conversions() {
         assert_eq!(OpCode::Known(Mnemonic::GAS).into_byte(), 0x5A);
@@ -0,0 +1,17 @@
+//! Commonly used traits and types.
+//!
+//! # Example
+//! ```
+//! use oculars_asm::prelude::*;
+//!
+//! let instruction = Instruction::disassemble(&[0x61, 0x0A, 0x0B]).unwrap();
+//!
+//! assert_eq!(instruction, Instruction::Push2(Push::new([0x0A, 0x0B])));
+//! assert_eq!(instruction.opcode(), OpCode::Known(Mnemonic::PUSH2));
+//! assert_eq!(instruction.size(), 3);
+//! ```
+
+pub use crate::{
+    AssemblyInstruction, DisassemblyError, Instruction, Mnemonic, OpCode,
+    instruction::{Dup, Log, Push, Swap, Unknown},
+};
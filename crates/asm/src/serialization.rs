@@ -0,0 +1,231 @@
+//! Serialization of instructions.
+//!
+//! An instruction is serialized as a map with its mnemonic and, for `PUSHx` instructions with an
+//! immediate value, the immediate as a `0x` prefixed hex string, e.g.
+//! `{ "mnemonic": "PUSH2", "immediate": "0x0a0b" }`. Unknown instructions are serialized with
+//! their raw byte, e.g. `{ "opcode": 15 }`.
+//!
+//! When deserializing, an optional `pc` field with the position of the instruction is accepted
+//! and ignored, so positioned instructions can be read back.
+
+use std::fmt;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+};
+
+use crate::{
+    AssemblyInstruction, Instruction, Mnemonic,
+    instruction::{Operand, Unknown},
+    text,
+};
+
+impl Serialize for Instruction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(mnemonic) = self.mnemonic() else {
+            let mut map = serializer.serialize_map(Some(1))?;
+            map.serialize_entry("opcode", &self.opcode().into_byte())?;
+            return map.end();
+        };
+
+        let operand = self.operand();
+        let mut map = serializer.serialize_map(Some(if operand.is_none() { 1 } else { 2 }))?;
+        map.serialize_entry("mnemonic", &mnemonic.to_string())?;
+
+        if !operand.is_none() {
+            map.serialize_entry("immediate", &operand.to_string())?;
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Instruction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(InstructionVisitor)
+    }
+}
+
+/// Deserializes an [`Instruction`] from a map.
+struct InstructionVisitor;
+
+impl<'de> Visitor<'de> for InstructionVisitor {
+    type Value = Instruction;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a map with a `mnemonic` and an optional `immediate`, or an `opcode`"
+        )
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut mnemonic: Option<String> = None;
+        let mut immediate: Option<String> = None;
+        let mut opcode: Option<u8> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "mnemonic" => mnemonic = Some(map.next_value()?),
+                "immediate" => immediate = Some(map.next_value()?),
+                "opcode" => opcode = Some(map.next_value()?),
+                "pc" => {
+                    map.next_value::<usize>()?;
+                }
+                _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+
+        match (mnemonic, immediate, opcode) {
+            (Some(name), immediate, None) => {
+                let mnemonic = Mnemonic::from_name(&name).ok_or_else(|| {
+                    de::Error::invalid_value(de::Unexpected::Str(&name), &"a known mnemonic")
+                })?;
+
+                let immediate = immediate.unwrap_or_default();
+                let operand = text::parse_operand(&immediate).map_err(de::Error::custom)?;
+                let expected = usize::from(mnemonic.push_size().unwrap_or_default());
+
+                if operand.len() != expected {
+                    return Err(de::Error::invalid_length(
+                        operand.len(),
+                        &format!("a {expected} byte immediate for `{mnemonic}`").as_str(),
+                    ));
+                }
+
+                Ok(text::assemble(mnemonic, &operand))
+            }
+            (None, None, Some(byte)) if Mnemonic::from_byte(byte).is_none() => {
+                Ok(Instruction::Unknown(Unknown::new(byte)))
+            }
+            (None, None, Some(byte)) => Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(u64::from(byte)),
+                &"an unknown opcode byte",
+            )),
+            _ => Err(de::Error::custom(
+                "expected either a `mnemonic` or an `opcode` field",
+            )),
+        }
+    }
+}
+
+/// Fields of a serialized instruction.
+const FIELDS: &[&str] = &["pc", "mnemonic", "immediate", "opcode"];
+
+impl Serialize for Operand<'_> {
+    /// Serializes an immediate as a `0x` prefixed hex string and no operand as `null`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::None => serializer.serialize_none(),
+            Self::Immediate(_) => serializer.collect_str(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::{Add, Dup, Log, Push, Swap};
+    use serde_json::json;
+
+    /// Serializes an instruction, checks the JSON and deserializes it back.
+    fn round_trip(instruction: Instruction, expected: &serde_json::Value) {
+        let value = serde_json::to_value(instruction).unwrap();
+        assert_eq!(&value, expected);
+        assert_eq!(
+            serde_json::from_value::<Instruction>(value).unwrap(),
+            instruction
+        );
+    }
+
+    #[test]
+    fn instruction_round_trips() {
+        round_trip(Instruction::Add(Add), &json!({ "mnemonic": "ADD" }));
+        round_trip(
+            Instruction::Push2(Push::new([0x0A, 0x0B])),
+            &json!({ "mnemonic": "PUSH2", "immediate": "0x0a0b" }),
+        );
+        round_trip(
+            Instruction::Push0(Push::new([])),
+            &json!({ "mnemonic": "PUSH0" }),
+        );
+        round_trip(
+            Instruction::Dup3(Dup::new()),
+            &json!({ "mnemonic": "DUP3" }),
+        );
+        round_trip(
+            Instruction::Swap16(Swap::new()),
+            &json!({ "mnemonic": "SWAP16" }),
+        );
+        round_trip(
+            Instruction::Log2(Log::new()),
+            &json!({ "mnemonic": "LOG2" }),
+        );
+        round_trip(
+            Instruction::Unknown(Unknown::new(0x0F)),
+            &json!({ "opcode": 15 }),
+        );
+    }
+
+    #[test]
+    fn every_instruction_round_trips() {
+        for byte in 0..=u8::MAX {
+            let mut bytes = vec![byte];
+            bytes.resize(33, 0xAB);
+
+            let instruction = Instruction::disassemble(&bytes).unwrap();
+            let json = serde_json::to_string(&instruction).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Instruction>(&json).unwrap(),
+                instruction
+            );
+        }
+    }
+
+    #[test]
+    fn pc_is_ignored() {
+        assert_eq!(
+            serde_json::from_value::<Instruction>(
+                json!({ "pc": 4, "mnemonic": "PUSH1", "immediate": "0x80" })
+            )
+            .unwrap(),
+            Instruction::Push1(Push::new([0x80]))
+        );
+        assert_eq!(
+            serde_json::from_value::<Instruction>(json!({ "opcode": 15, "pc": 0 })).unwrap(),
+            Instruction::Unknown(Unknown::new(0x0F))
+        );
+    }
+
+    #[test]
+    fn invalid_instructions_are_rejected() {
+        for json in [
+            json!({ "mnemonic": "PAY_VITALIK" }),
+            json!({ "mnemonic": "PUSH2", "immediate": "0x01" }),
+            json!({ "mnemonic": "ADD", "immediate": "0x01" }),
+            json!({ "mnemonic": "PUSH1", "immediate": "zz" }),
+            json!({ "opcode": 1 }),
+            json!({ "mnemonic": "ADD", "opcode": 15 }),
+            json!({ "pc": 1 }),
+            json!({ "pc": -1, "mnemonic": "ADD" }),
+            json!({ "position": 1, "mnemonic": "ADD" }),
+            json!({}),
+        ] {
+            assert!(
+                serde_json::from_value::<Instruction>(json.clone()).is_err(),
+                "{json}"
+            );
+        }
+    }
+
+    #[test]
+    fn operand_serialization() {
+        assert_eq!(
+            serde_json::to_value(Operand::Immediate(&[0xFF])).unwrap(),
+            json!("0xff")
+        );
+        assert_eq!(serde_json::to_value(Operand::None).unwrap(), json!(null));
+    }
+}
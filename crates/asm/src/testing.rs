@@ -0,0 +1,78 @@
+//! Helpers for writing tests against instructions.
+
+use crate::{Instruction, Mnemonic, text};
+
+/// Builds an instruction from a mnemonic name and a hex operand (with an optional `0x` prefix).
+/// Instructions without an immediate value take an empty operand.
+///
+/// # Example
+/// ```
+/// # use oculars_asm::{testing::instr, AssemblyInstruction, Instruction};
+/// let mut bytes: &[u8] = &[0x61, 0x0A, 0x0B, 0x5F, 0x01];
+/// let mut instructions = Vec::new();
+///
+/// while !bytes.is_empty() {
+///     let instruction = Instruction::disassemble(bytes).unwrap();
+///     bytes = &bytes[usize::from(instruction.size())..];
+///     instructions.push(instruction);
+/// }
+///
+/// assert_eq!(
+///     instructions,
+///     [instr("PUSH2", "0a0b"), instr("PUSH0", ""), instr("ADD", "")]
+/// );
+/// ```
+///
+/// # Panics
+/// Panics if the mnemonic is unknown, if the operand is not valid hex or if the operand size does
+/// not match the mnemonic.
+#[must_use]
+pub fn instr(mnemonic: &str, operand: &str) -> Instruction {
+    let parsed = Mnemonic::from_name(&mnemonic.to_uppercase())
+        .unwrap_or_else(|| panic!("unknown mnemonic `{mnemonic}`"));
+
+    let bytes = text::parse_operand(operand)
+        .unwrap_or_else(|_| panic!("invalid operand `{operand}` for `{mnemonic}`"));
+
+    let expected = usize::from(parsed.push_size().unwrap_or_default());
+    assert!(
+        bytes.len() == expected,
+        "`{mnemonic}` expects a {expected} byte operand, got {} bytes",
+        bytes.len()
+    );
+
+    text::assemble(parsed, &bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::{Add, Push};
+
+    #[test]
+    fn it_builds_instructions() {
+        assert_eq!(instr("ADD", ""), Instruction::Add(Add));
+        assert_eq!(
+            instr("push2", "0x0a0b"),
+            Instruction::Push2(Push::new([0x0A, 0x0B]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown mnemonic `PUSH33`")]
+    fn it_panics_on_unknown_mnemonics() {
+        let _instruction = instr("PUSH33", "");
+    }
+
+    #[test]
+    #[should_panic(expected = "`PUSH2` expects a 2 byte operand, got 1 bytes")]
+    fn it_panics_on_operand_size_mismatch() {
+        let _instruction = instr("PUSH2", "0a");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid operand `zz` for `PUSH1`")]
+    fn it_panics_on_invalid_operands() {
+        let _instruction = instr("PUSH1", "zz");
+    }
+}
@@ -0,0 +1,274 @@
+//! Textual EVM assembly.
+
+use thiserror::Error;
+
+use crate::{AssemblyInstruction, Instruction, Mnemonic};
+
+/// An error that can happen while parsing textual assembly.
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("line {line}: {kind}")]
+pub struct ParseError {
+    /// The line (starting from 1) on which the error happened.
+    pub line: usize,
+    /// The kind of the error.
+    pub kind: ParseErrorKind,
+}
+
+/// The kind of a [`ParseError`].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The mnemonic is not known.
+    #[error("unknown mnemonic `{0}`")]
+    UnknownMnemonic(String),
+
+    /// The operand is not a valid hex string.
+    #[error("invalid operand `{0}`")]
+    InvalidOperand(String),
+
+    /// The size of the operand does not match the size expected by the mnemonic.
+    #[error("`{mnemonic}` expects a {expected} byte operand, got {got} bytes")]
+    OperandSize {
+        /// The mnemonic that received the operand.
+        mnemonic: Mnemonic,
+        /// Expected operand size in bytes.
+        expected: usize,
+        /// Received operand size in bytes.
+        got: usize,
+    },
+
+    /// The operand of a sizeless `PUSH` does not fit into any `PUSHx` instruction.
+    #[error("a {0} byte operand does not fit into any `PUSHx`")]
+    OperandTooLarge(usize),
+
+    /// A line contains more tokens than a mnemonic and its operand.
+    #[error("unexpected token `{0}`")]
+    UnexpectedToken(String),
+}
+
+/// Parses a textual assembly listing into a list of instructions.
+///
+/// Every line contains a single mnemonic, optionally followed by a hex operand for `PUSHx`
+/// instructions. A `PUSH` without a size is assembled into the smallest `PUSHx` that fits its
+/// operand. Mnemonics are case insensitive. Comments starting with `//` or `;` and blank lines are
+/// ignored.
+///
+/// # Example
+/// ```
+/// # use oculars_asm::{text::parse, instruction::*};
+/// let instructions = parse("
+///     PUSH1 0x60 // free memory pointer
+///     push2 0x0102
+///     PUSH 0x1234
+///     ADD
+///     STOP       ; done
+/// ").unwrap();
+///
+/// assert_eq!(instructions, vec![
+///     Instruction::Push1(Push::new([0x60])),
+///     Instruction::Push2(Push::new([0x01, 0x02])),
+///     Instruction::Push2(Push::new([0x12, 0x34])),
+///     Instruction::Add(Add),
+///     Instruction::Stop(Stop),
+/// ]);
+/// ```
+///
+/// # Errors
+/// Returns an error with the offending line number if a mnemonic is unknown or if an operand is
+/// malformed or does not match the size expected by the mnemonic.
+pub fn parse(source: &str) -> Result<Vec<Instruction>, ParseError> {
+    source
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            parse_line(line)
+                .map_err(|kind| ParseError {
+                    line: index + 1,
+                    kind,
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+/// Parses a single line of assembly. Returns [`None`] if the line contains no instruction.
+fn parse_line(line: &str) -> Result<Option<Instruction>, ParseErrorKind> {
+    let line = strip_comment(line);
+    let mut tokens = line.split_whitespace();
+
+    let Some(name) = tokens.next() else {
+        return Ok(None);
+    };
+
+    let operand = match tokens.next() {
+        Some(operand) => parse_operand(operand)?,
+        None => Vec::new(),
+    };
+
+    if let Some(token) = tokens.next() {
+        return Err(ParseErrorKind::UnexpectedToken(token.to_string()));
+    }
+
+    let mnemonic = if name.eq_ignore_ascii_case("PUSH") {
+        Mnemonic::smallest_push_for(operand.len())
+            .ok_or(ParseErrorKind::OperandTooLarge(operand.len()))?
+    } else {
+        Mnemonic::from_name(&name.to_uppercase())
+            .ok_or_else(|| ParseErrorKind::UnknownMnemonic(name.to_string()))?
+    };
+
+    let expected = usize::from(mnemonic.push_size().unwrap_or_default());
+
+    if operand.len() != expected {
+        return Err(ParseErrorKind::OperandSize {
+            mnemonic,
+            expected,
+            got: operand.len(),
+        });
+    }
+
+    Ok(Some(assemble(mnemonic, &operand)))
+}
+
+/// Removes a trailing comment from a line.
+fn strip_comment(line: &str) -> &str {
+    let end = [line.find("//"), line.find(';')]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(line.len());
+
+    &line[..end]
+}
+
+/// Parses a hex operand with an optional `0x` prefix.
+pub(crate) fn parse_operand(operand: &str) -> Result<Vec<u8>, ParseErrorKind> {
+    let invalid = || ParseErrorKind::InvalidOperand(operand.to_string());
+    let digits = operand.strip_prefix("0x").unwrap_or(operand);
+
+    // `from_str_radix` accepts a leading `+`, so the digits are validated up front.
+    if !digits.len().is_multiple_of(2) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| {
+            digits
+                .get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+/// Assembles a mnemonic and its operand into an instruction.
+pub(crate) fn assemble(mnemonic: Mnemonic, operand: &[u8]) -> Instruction {
+    let mut bytes = Vec::with_capacity(operand.len() + 1);
+    bytes.push(mnemonic.into_byte());
+    bytes.extend_from_slice(operand);
+
+    Instruction::disassemble(&bytes).expect("the operand size matches the mnemonic")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::{Add, Push, Stop};
+
+    #[test]
+    fn it_parses_listings() {
+        let instructions = parse("PUSH1 0x60\nPUSH0\n\n  // comment\nadd ; comment\nSTOP").unwrap();
+
+        assert_eq!(
+            instructions,
+            vec![
+                Instruction::Push1(Push::new([0x60])),
+                Instruction::Push0(Push::new([])),
+                Instruction::Add(Add),
+                Instruction::Stop(Stop),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_unknown_mnemonics() {
+        assert_eq!(
+            parse("ADD\nPAY_VITALIK").unwrap_err(),
+            ParseError {
+                line: 2,
+                kind: ParseErrorKind::UnknownMnemonic("PAY_VITALIK".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn it_validates_operands() {
+        assert_eq!(
+            parse("PUSH2 0x01").unwrap_err(),
+            ParseError {
+                line: 1,
+                kind: ParseErrorKind::OperandSize {
+                    mnemonic: Mnemonic::PUSH2,
+                    expected: 2,
+                    got: 1
+                }
+            }
+        );
+
+        assert_eq!(
+            parse("ADD 0x01").unwrap_err().kind,
+            ParseErrorKind::OperandSize {
+                mnemonic: Mnemonic::ADD,
+                expected: 0,
+                got: 1
+            }
+        );
+
+        assert_eq!(
+            parse("PUSH1 0x6").unwrap_err().kind,
+            ParseErrorKind::InvalidOperand("0x6".to_string())
+        );
+
+        assert_eq!(
+            parse("PUSH1 0xZZ").unwrap_err().kind,
+            ParseErrorKind::InvalidOperand("0xZZ".to_string())
+        );
+
+        assert_eq!(
+            parse("PUSH1 0x+1").unwrap_err().kind,
+            ParseErrorKind::InvalidOperand("0x+1".to_string())
+        );
+
+        assert_eq!(
+            parse("PUSH1 0x01 0x02").unwrap_err().kind,
+            ParseErrorKind::UnexpectedToken("0x02".to_string())
+        );
+    }
+
+    #[test]
+    fn it_infers_push_sizes() {
+        assert_eq!(
+            parse("PUSH 0x1234\npush\nPUSH 0x00").unwrap(),
+            vec![
+                Instruction::Push2(Push::new([0x12, 0x34])),
+                Instruction::Push0(Push::new([])),
+                Instruction::Push1(Push::new([0x00])),
+            ]
+        );
+
+        assert_eq!(
+            parse(&format!("PUSH 0x{}", "ff".repeat(33)))
+                .unwrap_err()
+                .kind,
+            ParseErrorKind::OperandTooLarge(33)
+        );
+    }
+
+    #[test]
+    fn parse_error_fmt() {
+        assert_eq!(
+            parse("\nFOO").unwrap_err().to_string(),
+            "line 2: unknown mnemonic `FOO`"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Layer 2 and sidechain networks.
+//!
+//! These chains share the EVM semantics of Ethereum, but use their own chain ids for
+//! [EIP-155](https://eips.ethereum.org/EIPS/eip-155) replay protection.
+
+use crate::Chain;
+
+/// OP Mainnet (Optimism).
+///
+/// # Example
+/// ```
+/// # use oculars_chains::{Chain, l2::Optimism};
+/// assert_eq!(Optimism::CHAIN_ID, 10);
+/// ```
+pub struct Optimism;
+
+impl Chain for Optimism {
+    const CHAIN_ID: u64 = 10;
+    const NETWORK_ID: u64 = 10;
+}
+
+/// Base.
+///
+/// # Example
+/// ```
+/// # use oculars_chains::{Chain, l2::Base};
+/// assert_eq!(Base::CHAIN_ID, 8453);
+/// ```
+pub struct Base;
+
+impl Chain for Base {
+    const CHAIN_ID: u64 = 8453;
+    const NETWORK_ID: u64 = 8453;
+}
+
+/// Arbitrum One.
+///
+/// # Example
+/// ```
+/// # use oculars_chains::{Chain, l2::ArbitrumOne};
+/// assert_eq!(ArbitrumOne::CHAIN_ID, 42161);
+/// ```
+pub struct ArbitrumOne;
+
+impl Chain for ArbitrumOne {
+    const CHAIN_ID: u64 = 42161;
+    const NETWORK_ID: u64 = 42161;
+}
+
+/// Polygon proof-of-stake chain.
+///
+/// # Example
+/// ```
+/// # use oculars_chains::{Chain, l2::PolygonPoS};
+/// assert_eq!(PolygonPoS::CHAIN_ID, 137);
+/// ```
+pub struct PolygonPoS;
+
+impl Chain for PolygonPoS {
+    const CHAIN_ID: u64 = 137;
+    const NETWORK_ID: u64 = 137;
+}
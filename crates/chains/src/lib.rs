@@ -1,5 +1,7 @@
 //! Ethereum chains.
 
+pub mod l2;
+
 /// Chain information.
 pub trait Chain {
     /// Chain id.
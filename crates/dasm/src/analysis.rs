@@ -0,0 +1,722 @@
+//! Static bytecode analysis.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use asm::{AssemblyInstruction, Instruction, Mnemonic, OpCode};
+use upgrades::hardfork::HardFork;
+
+use crate::assembly::PositionedInstruction;
+
+/// A straight-line sequence of instructions with a single entry and a single exit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasicBlock {
+    /// Position of the first instruction.
+    pub start: usize,
+    /// Position of the last instruction.
+    pub end: usize,
+    /// The instructions of this block.
+    pub instructions: Vec<PositionedInstruction>,
+}
+
+/// Returns the number of bytes consumed by the instruction at the program counter `pc`.
+///
+/// The span includes the opcode and its immediate value and is clamped to the end of the
+/// bytecode, so `&bytecode[pc..pc + span]` never indexes out of range. Returns `0` if `pc` is out
+/// of bounds.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::max_immediate_span;
+/// // PUSH2 0x0102 ADD PUSH32 0x01
+/// let bytecode = [0x61, 0x01, 0x02, 0x01, 0x7f, 0x01];
+///
+/// assert_eq!(max_immediate_span(&bytecode, 0), 3);
+/// assert_eq!(max_immediate_span(&bytecode, 3), 1);
+/// assert_eq!(max_immediate_span(&bytecode, 4), 2);
+/// assert_eq!(max_immediate_span(&bytecode, 6), 0);
+/// ```
+#[must_use]
+pub fn max_immediate_span(bytecode: &[u8], pc: usize) -> usize {
+    let Some(&byte) = bytecode.get(pc) else {
+        return 0;
+    };
+
+    let size = 1 + immediate_size(OpCode::from_byte(byte));
+
+    size.min(bytecode.len() - pc)
+}
+
+/// Returns the immediate value of the `PUSHx` instruction at the program counter `pc`.
+///
+/// Returns [`None`] if `pc` is out of bounds, if the opcode at `pc` has no immediate (including
+/// `PUSH0`) or if the immediate is truncated by the end of the bytecode.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::immediate_at;
+/// // PUSH2 0x0102 ADD PUSH0 PUSH32 0x01
+/// let bytecode = [0x61, 0x01, 0x02, 0x01, 0x5F, 0x7f, 0x01];
+///
+/// assert_eq!(immediate_at(&bytecode, 0), Some(&[0x01, 0x02][..]));
+/// assert_eq!(immediate_at(&bytecode, 3), None);
+/// assert_eq!(immediate_at(&bytecode, 4), None);
+/// assert_eq!(immediate_at(&bytecode, 5), None);
+/// ```
+#[must_use]
+pub fn immediate_at(bytecode: &[u8], pc: usize) -> Option<&[u8]> {
+    let size = immediate_size(OpCode::from_byte(*bytecode.get(pc)?));
+
+    if size == 0 {
+        return None;
+    }
+
+    bytecode.get(pc + 1..=pc + size)
+}
+
+/// Returns the positions of all valid `JUMPDEST` instructions in ascending order. `JUMPDEST`
+/// bytes that are part of a `PUSHx` immediate are not valid jump destinations.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::jump_destinations;
+/// // JUMPDEST PUSH1 0x5B JUMPDEST
+/// assert_eq!(jump_destinations(&[0x5B, 0x60, 0x5B, 0x5B]), vec![0, 3]);
+/// ```
+#[must_use]
+pub fn jump_destinations(bytecode: &[u8]) -> Vec<usize> {
+    opcodes(bytecode)
+        .filter(|&(_, opcode)| opcode == OpCode::Known(Mnemonic::JUMPDEST))
+        .map(|(pc, _)| pc)
+        .collect()
+}
+
+/// Returns `(jump_pc, target)` pairs for every `JUMP` or `JUMPI` whose destination is pushed by
+/// the immediately preceding `PUSHx` instruction and is a valid `JUMPDEST`.
+///
+/// Computed jumps and jumps to invalid destinations are ignored.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::static_jumps;
+/// // PUSH1 0x04 JUMP INVALID JUMPDEST STOP
+/// assert_eq!(static_jumps(&[0x60, 0x04, 0x56, 0xFE, 0x5B, 0x00]), vec![(2, 4)]);
+/// ```
+#[must_use]
+pub fn static_jumps(bytecode: &[u8]) -> Vec<(usize, usize)> {
+    let destinations = jump_destinations(bytecode);
+    let mut jumps = Vec::new();
+    let mut previous: Option<(usize, OpCode)> = None;
+
+    for (pc, opcode) in opcodes(bytecode) {
+        let is_jump = matches!(opcode, OpCode::Known(mnemonic) if mnemonic.is_jump());
+
+        if let Some((push_pc, push)) = previous
+            && is_jump
+            && push.is_push()
+            && let Some(target) = immediate_value(&bytecode[push_pc + 1..pc])
+            && destinations.binary_search(&target).is_ok()
+        {
+            jumps.push((pc, target));
+        }
+
+        previous = Some((pc, opcode));
+    }
+
+    jumps
+}
+
+/// Returns `(pc, immediate)` pairs for every `PUSHx` instruction with an immediate value, which
+/// surfaces constants like addresses, function selectors and magic numbers. `PUSH0` has no
+/// immediate and is skipped. An immediate truncated by the end of the bytecode is padded with
+/// zeros, like the EVM does.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::pushed_constants;
+/// // PUSH4 0xa9059cbb EQ PUSH0 PUSH1 0x60
+/// let bytecode = [0x63, 0xa9, 0x05, 0x9c, 0xbb, 0x14, 0x5F, 0x60, 0x60];
+/// assert_eq!(
+///     pushed_constants(&bytecode),
+///     vec![(0, vec![0xa9, 0x05, 0x9c, 0xbb]), (7, vec![0x60])]
+/// );
+/// ```
+#[must_use]
+pub fn pushed_constants(bytecode: &[u8]) -> Vec<(usize, Vec<u8>)> {
+    opcodes(bytecode)
+        .filter(|&(_, opcode)| immediate_size(opcode) > 0)
+        .map(|(pc, opcode)| {
+            let mut immediate = bytecode[pc + 1..pc + max_immediate_span(bytecode, pc)].to_vec();
+            immediate.resize(immediate_size(opcode), 0);
+            (pc, immediate)
+        })
+        .collect()
+}
+
+/// Returns the 4-byte function selectors that are matched by a function dispatcher, in the order
+/// in which they appear.
+///
+/// A selector is recognized in the pattern `PUSH4 <selector> EQ PUSHx <dest> JUMPI`, where the
+/// destination is pushed by a `PUSH1` or `PUSH2` instruction. The pattern may be preceded by a
+/// `DUP1` that copies the selector from the calldata.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::function_selectors;
+/// // DUP1 PUSH4 0xa9059cbb EQ PUSH2 0x0040 JUMPI
+/// let bytecode = [0x80, 0x63, 0xa9, 0x05, 0x9c, 0xbb, 0x14, 0x61, 0x00, 0x40, 0x57];
+/// assert_eq!(function_selectors(&bytecode), vec![[0xa9, 0x05, 0x9c, 0xbb]]);
+/// ```
+#[must_use]
+pub fn function_selectors(bytecode: &[u8]) -> Vec<[u8; 4]> {
+    use Mnemonic::{EQ, JUMPI, PUSH1, PUSH2, PUSH4};
+
+    let opcodes: Vec<_> = opcodes(bytecode).collect();
+
+    opcodes
+        .windows(4)
+        .filter_map(|window| match window {
+            [
+                (pc, OpCode::Known(PUSH4)),
+                (_, OpCode::Known(EQ)),
+                (_, OpCode::Known(PUSH1 | PUSH2)),
+                (_, OpCode::Known(JUMPI)),
+            ] => immediate_at(bytecode, *pc)?.try_into().ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Splits bytecode into basic blocks.
+///
+/// A block starts at position `0`, at a `JUMPDEST` or after the end of the previous block. A block
+/// ends at a terminator (see [`OpCode::is_terminator`]), at a `JUMP` or `JUMPI`, or at the
+/// instruction before the next `JUMPDEST`. Both the designated `INVALID` instruction and unknown
+/// opcode bytes halt execution and end a block (see [`OpCode::is_designated_invalid`]). A `PUSHx`
+/// immediate truncated by the end of the bytecode is padded with zeros, like the EVM does.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::basic_blocks;
+/// // PUSH1 0x04 JUMP INVALID JUMPDEST STOP
+/// let blocks = basic_blocks(&[0x60, 0x04, 0x56, 0xFE, 0x5B, 0x00]);
+/// let bounds: Vec<_> = blocks.iter().map(|block| (block.start, block.end)).collect();
+/// assert_eq!(bounds, vec![(0, 2), (3, 3), (4, 5)]);
+/// ```
+#[must_use]
+pub fn basic_blocks(bytecode: &[u8]) -> Vec<BasicBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Vec<PositionedInstruction> = Vec::new();
+
+    for (position, opcode) in opcodes(bytecode) {
+        if opcode == OpCode::Known(Mnemonic::JUMPDEST) && !current.is_empty() {
+            blocks.push(BasicBlock::from_instructions(std::mem::take(&mut current)));
+        }
+
+        current.push(PositionedInstruction {
+            position,
+            instruction: decode(bytecode, position),
+        });
+
+        let ends_block = opcode.is_terminator()
+            || matches!(opcode, OpCode::Known(mnemonic) if mnemonic.is_jump());
+
+        if ends_block {
+            blocks.push(BasicBlock::from_instructions(std::mem::take(&mut current)));
+        }
+    }
+
+    if !current.is_empty() {
+        blocks.push(BasicBlock::from_instructions(current));
+    }
+
+    blocks
+}
+
+impl BasicBlock {
+    /// Creates a block out of a non-empty list of instructions.
+    fn from_instructions(instructions: Vec<PositionedInstruction>) -> Self {
+        Self {
+            start: instructions.first().map_or(0, |first| first.position),
+            end: instructions.last().map_or(0, |last| last.position),
+            instructions,
+        }
+    }
+}
+
+/// Decodes the instruction at `pc`, padding a truncated immediate with zeros.
+fn decode(bytecode: &[u8], pc: usize) -> Instruction {
+    let span = max_immediate_span(bytecode, pc);
+    let mut bytes = bytecode[pc..pc + span].to_vec();
+    bytes.resize(1 + immediate_size(OpCode::from_byte(bytecode[pc])), 0);
+
+    Instruction::disassemble(&bytes).expect("the immediate is padded to the expected size")
+}
+
+/// Counts how often each opcode occurs in the bytecode. `PUSHx` immediates are skipped.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::opcode_histogram;
+/// # use asm::{Mnemonic, OpCode};
+/// // PUSH1 0x01 PUSH1 0x01 ADD
+/// let histogram = opcode_histogram(&[0x60, 0x01, 0x60, 0x01, 0x01]);
+/// assert_eq!(histogram[&OpCode::Known(Mnemonic::PUSH1)], 2);
+/// assert_eq!(histogram[&OpCode::Known(Mnemonic::ADD)], 1);
+/// ```
+#[must_use]
+pub fn opcode_histogram(bytecode: &[u8]) -> BTreeMap<OpCode, usize> {
+    let mut histogram = BTreeMap::new();
+
+    for (_, opcode) in opcodes(bytecode) {
+        *histogram.entry(opcode).or_default() += 1;
+    }
+
+    histogram
+}
+
+/// Returns the distinct known mnemonics used by the bytecode. `PUSHx` immediates and unknown
+/// opcodes are skipped.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::opcode_coverage;
+/// # use asm::Mnemonic;
+/// // PUSH1 0x01 PUSH1 0x01 ADD 0x0C
+/// let coverage = opcode_coverage(&[0x60, 0x01, 0x60, 0x01, 0x01, 0x0C]);
+/// assert!(coverage.into_iter().eq([Mnemonic::ADD, Mnemonic::PUSH1]));
+/// ```
+#[must_use]
+pub fn opcode_coverage(bytecode: &[u8]) -> BTreeSet<Mnemonic> {
+    opcodes(bytecode)
+        .filter_map(|(_, opcode)| match opcode {
+            OpCode::Known(mnemonic) => Some(mnemonic),
+            OpCode::Unknown(_) => None,
+        })
+        .collect()
+}
+
+/// Returns the earliest hard fork that supports every known mnemonic used by the bytecode, i.e. the
+/// latest [`HardFork::introducing`] among them. Unknown opcodes are ignored, see
+/// [`opcode_coverage`].
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::minimum_fork;
+/// # use upgrades::hardfork::HardFork;
+/// // PUSH0 PUSH1 0x01 ADD
+/// assert_eq!(minimum_fork(&[0x5F, 0x60, 0x01, 0x01]), HardFork::Shanghai);
+/// assert_eq!(minimum_fork(&[0x60, 0x01, 0x01]), HardFork::Frontier);
+/// ```
+#[must_use]
+pub fn minimum_fork(bytecode: &[u8]) -> HardFork {
+    opcode_coverage(bytecode)
+        .into_iter()
+        .filter_map(HardFork::introducing)
+        .max()
+        .unwrap_or(HardFork::Frontier)
+}
+
+/// Returns `(pc, mnemonic, gas, dynamic)` for every known instruction, where `gas` is the static
+/// base gas cost of the mnemonic and `dynamic` tells whether it has an additional dynamic cost.
+/// `PUSHx` immediates and unknown opcodes are skipped.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::analysis::static_gas_trace;
+/// # use asm::Mnemonic;
+/// // PUSH1 0x01 PUSH1 0x00 MSTORE
+/// assert_eq!(
+///     static_gas_trace(&[0x60, 0x01, 0x60, 0x00, 0x52]),
+///     [
+///         (0, Mnemonic::PUSH1, 3, false),
+///         (2, Mnemonic::PUSH1, 3, false),
+///         (4, Mnemonic::MSTORE, 3, true),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn static_gas_trace(bytecode: &[u8]) -> Vec<(usize, Mnemonic, u64, bool)> {
+    opcodes(bytecode)
+        .filter_map(|(pc, opcode)| match opcode {
+            OpCode::Known(mnemonic) => Some((
+                pc,
+                mnemonic,
+                mnemonic.base_gas_cost(),
+                mnemonic.has_dynamic_gas(),
+            )),
+            OpCode::Unknown(_) => None,
+        })
+        .collect()
+}
+
+/// Returns an iterator over the positions and opcodes of all instructions, skipping immediates.
+fn opcodes(bytecode: &[u8]) -> impl Iterator<Item = (usize, OpCode)> {
+    let mut pc = 0;
+
+    std::iter::from_fn(move || {
+        let byte = *bytecode.get(pc)?;
+        let position = pc;
+        pc += max_immediate_span(bytecode, pc);
+
+        Some((position, OpCode::from_byte(byte)))
+    })
+}
+
+/// Interprets a big-endian immediate as a position. Returns [`None`] if it does not fit.
+fn immediate_value(immediate: &[u8]) -> Option<usize> {
+    let significant = immediate
+        .iter()
+        .position(|&byte| byte != 0)
+        .map_or(&[][..], |start| &immediate[start..]);
+
+    if significant.len() > size_of::<usize>() {
+        return None;
+    }
+
+    Some(
+        significant
+            .iter()
+            .fold(0, |value, &byte| (value << 8) | usize::from(byte)),
+    )
+}
+
+/// Returns the size of the immediate value that follows an opcode.
+fn immediate_size(opcode: OpCode) -> usize {
+    usize::from(opcode.push_size().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asm::instruction::{Invalid, JumpDest, Push, Unknown};
+
+    #[test]
+    fn immediates() {
+        // PUSH1 0x5B JUMPDEST PUSH4 0xa9059cbb
+        let bytecode = [0x60, 0x5B, 0x5B, 0x63, 0xa9, 0x05, 0x9c, 0xbb];
+
+        assert_eq!(immediate_at(&bytecode, 0), Some(&[0x5B][..]));
+        assert_eq!(immediate_at(&bytecode, 1), None);
+        assert_eq!(immediate_at(&bytecode, 2), None);
+        assert_eq!(
+            immediate_at(&bytecode, 3),
+            Some(&[0xa9, 0x05, 0x9c, 0xbb][..])
+        );
+        assert_eq!(immediate_at(&bytecode, 8), None);
+        assert_eq!(immediate_at(&bytecode, usize::MAX), None);
+
+        // Truncated PUSH4 and PUSH32.
+        for len in 4..bytecode.len() {
+            assert_eq!(immediate_at(&bytecode[..len], 3), None);
+        }
+        assert_eq!(immediate_at(&[0x7f], 0), None);
+        assert_eq!(immediate_at(&[0x7f; 32], 0), None);
+        assert_eq!(immediate_at(&[0x7f; 33], 0), Some(&[0x7f; 32][..]));
+    }
+
+    #[test]
+    fn span_of_trailing_push32() {
+        let mut bytecode = vec![0x7f];
+        bytecode.extend([0xff; 32]);
+
+        assert_eq!(max_immediate_span(&bytecode, 0), 33);
+
+        for len in 0..=32 {
+            let truncated = &bytecode[..=len];
+            assert_eq!(max_immediate_span(truncated, 0), len + 1);
+        }
+    }
+
+    #[test]
+    fn span_inside_immediate() {
+        // PUSH1 0x60 PUSH32 ...
+        let bytecode = [0x60, 0x60, 0x7f, 0x00];
+
+        assert_eq!(max_immediate_span(&bytecode, 1), 2);
+        assert_eq!(max_immediate_span(&bytecode, 2), 2);
+        assert_eq!(max_immediate_span(&bytecode, 3), 1);
+    }
+
+    #[test]
+    fn jump_destinations_skip_immediates() {
+        // PUSH2 0x5B5B JUMPDEST PUSH32 0x5B... (truncated)
+        let bytecode = [0x61, 0x5B, 0x5B, 0x5B, 0x7F, 0x5B, 0x5B];
+        assert_eq!(jump_destinations(&bytecode), vec![3]);
+        assert!(jump_destinations(&[]).is_empty());
+    }
+
+    #[test]
+    fn resolvable_jumps() {
+        // 0x00 PUSH1 0x08
+        // 0x02 JUMPI
+        // 0x03 PUSH2 0x0008
+        // 0x06 JUMP
+        // 0x07 INVALID
+        // 0x08 JUMPDEST
+        // 0x09 PUSH0
+        // 0x0a JUMP
+        let bytecode = [
+            0x60, 0x08, 0x57, 0x61, 0x00, 0x08, 0x56, 0xFE, 0x5B, 0x5F, 0x56,
+        ];
+
+        assert_eq!(static_jumps(&bytecode), vec![(2, 8), (6, 8)]);
+    }
+
+    #[test]
+    fn unresolvable_jumps() {
+        // PUSH1 0x07 JUMP (not a JUMPDEST)
+        assert!(static_jumps(&[0x60, 0x07, 0x56, 0x00]).is_empty());
+
+        // CALLDATALOAD JUMP (computed)
+        assert!(static_jumps(&[0x35, 0x56, 0x5B]).is_empty());
+
+        // PUSH1 0x04 POP JUMP JUMPDEST (push does not immediately precede the jump)
+        assert!(static_jumps(&[0x60, 0x04, 0x50, 0x56, 0x5B]).is_empty());
+
+        // PUSH32 with a target that doesn't fit into `usize`
+        let mut bytecode = vec![0x7F];
+        bytecode.extend([0xFF; 32]);
+        bytecode.extend([0x56, 0x5B]);
+        assert!(static_jumps(&bytecode).is_empty());
+    }
+
+    #[test]
+    fn blocks_split_at_jumpdest() {
+        // 0x00 PUSH1 0x80
+        // 0x02 PUSH1 0x40
+        // 0x04 MSTORE
+        // 0x05 JUMPDEST
+        // 0x06 CALLVALUE
+        // 0x07 STOP
+        let blocks = basic_blocks(&[0x60, 0x80, 0x60, 0x40, 0x52, 0x5B, 0x34, 0x00]);
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!((blocks[0].start, blocks[0].end), (0, 4));
+        assert_eq!(blocks[0].instructions.len(), 3);
+        assert_eq!((blocks[1].start, blocks[1].end), (5, 7));
+        assert_eq!(
+            blocks[1].instructions[0].instruction,
+            Instruction::JumpDest(JumpDest)
+        );
+    }
+
+    #[test]
+    fn blocks_split_after_jumps_and_terminators() {
+        // PUSH1 0x06 JUMPI ADD STOP JUMPDEST JUMPDEST
+        let blocks = basic_blocks(&[0x60, 0x06, 0x57, 0x01, 0x00, 0x5B, 0x5B]);
+        let bounds: Vec<_> = blocks
+            .iter()
+            .map(|block| (block.start, block.end))
+            .collect();
+
+        assert_eq!(bounds, vec![(0, 2), (3, 4), (5, 5), (6, 6)]);
+        assert!(basic_blocks(&[]).is_empty());
+
+        // ADD 0x0C ADD
+        assert_eq!(basic_blocks(&[0x01, 0x0C, 0x01]).len(), 2);
+    }
+
+    #[test]
+    fn blocks_pad_truncated_immediates() {
+        let blocks = basic_blocks(&[0x00, 0x61, 0x01]);
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(
+            blocks[1].instructions[0].instruction,
+            Instruction::Push2(Push::new([0x01, 0x00]))
+        );
+    }
+
+    #[test]
+    fn pushed_constants_skip_push_data() {
+        // PUSH20 <address> PUSH1 0x60 (0x60 is push data) PUSH2 0x6001 JUMPDEST PUSH0 PUSH3 0xAB
+        let mut bytecode = vec![0x73];
+        bytecode.extend([0x11; 20]);
+        bytecode.extend([0x60, 0x60, 0x61, 0x60, 0x01, 0x5B, 0x5F, 0x62, 0xAB]);
+
+        assert_eq!(
+            pushed_constants(&bytecode),
+            vec![
+                (0, vec![0x11; 20]),
+                (21, vec![0x60]),
+                (23, vec![0x60, 0x01]),
+                (28, vec![0xAB, 0x00, 0x00]),
+            ]
+        );
+
+        assert!(pushed_constants(&[]).is_empty());
+        assert!(pushed_constants(&[0x5F, 0x01, 0x00]).is_empty());
+    }
+
+    #[test]
+    fn selectors_of_a_dispatcher() {
+        let bytecode = [
+            // PUSH1 0xE0 CALLDATALOAD PUSH1 0xE0 SHR
+            0x60, 0xE0, 0x35, 0x60, 0xE0, 0x1C, //
+            // DUP1 PUSH4 0x70a08231 EQ PUSH2 0x0030 JUMPI
+            0x80, 0x63, 0x70, 0xa0, 0x82, 0x31, 0x14, 0x61, 0x00, 0x30, 0x57, //
+            // PUSH4 0xa9059cbb EQ PUSH1 0x40 JUMPI
+            0x63, 0xa9, 0x05, 0x9c, 0xbb, 0x14, 0x60, 0x40, 0x57, //
+            // PUSH4 0x01020304 GT PUSH1 0x50 JUMPI (not a selector match)
+            0x63, 0x01, 0x02, 0x03, 0x04, 0x11, 0x60, 0x50, 0x57, //
+            // PUSH0 DUP1 REVERT
+            0x5F, 0x80, 0xFD,
+        ];
+
+        assert_eq!(
+            function_selectors(&bytecode),
+            vec![[0x70, 0xa0, 0x82, 0x31], [0xa9, 0x05, 0x9c, 0xbb]]
+        );
+    }
+
+    #[test]
+    fn selectors_ignore_push_data() {
+        // PUSH12 <PUSH4 0x01020304 EQ PUSH1 0x40 JUMPI> STOP
+        let bytecode = [
+            0x6B, 0x63, 0x01, 0x02, 0x03, 0x04, 0x14, 0x60, 0x40, 0x57, 0x00, 0x00, 0x00, 0x00,
+        ];
+        assert!(function_selectors(&bytecode).is_empty());
+        assert!(function_selectors(&[]).is_empty());
+    }
+
+    #[test]
+    fn blocks_end_at_invalid_and_unknown_bytes() {
+        // GAS INVALID GAS
+        let blocks = basic_blocks(&[0x5A, 0xFE, 0x5A]);
+        let bounds: Vec<_> = blocks
+            .iter()
+            .map(|block| (block.start, block.end))
+            .collect();
+        assert_eq!(bounds, vec![(0, 1), (2, 2)]);
+        assert_eq!(
+            blocks[0].instructions[1].instruction,
+            Instruction::Invalid(Invalid)
+        );
+
+        // GAS 0x0F GAS
+        let blocks = basic_blocks(&[0x5A, 0x0F, 0x5A]);
+        let bounds: Vec<_> = blocks
+            .iter()
+            .map(|block| (block.start, block.end))
+            .collect();
+        assert_eq!(bounds, vec![(0, 1), (2, 2)]);
+        assert_eq!(
+            blocks[0].instructions[1].instruction,
+            Instruction::Unknown(Unknown::new(0x0F))
+        );
+    }
+
+    #[test]
+    fn coverage_of_sample() {
+        use upgrades::execution::{ExecutionUpgrade, london::London, shanghai::Shanghai};
+
+        // PUSH1 0x80 PUSH1 0x40 MSTORE PUSH0 PUSH2 0x5F5F CALLVALUE 0x0C STOP STOP
+        let bytecode = [
+            0x60, 0x80, 0x60, 0x40, 0x52, 0x5F, 0x61, 0x5F, 0x5F, 0x34, 0x0C, 0x00, 0x00,
+        ];
+        let coverage = opcode_coverage(&bytecode);
+
+        assert_eq!(
+            coverage.iter().copied().collect::<Vec<_>>(),
+            [
+                Mnemonic::STOP,
+                Mnemonic::CALLVALUE,
+                Mnemonic::MSTORE,
+                Mnemonic::PUSH0,
+                Mnemonic::PUSH1,
+                Mnemonic::PUSH2,
+            ]
+        );
+        assert!(
+            !coverage
+                .iter()
+                .all(|&mnemonic| London::supports_mnemonic(mnemonic))
+        );
+        assert!(
+            coverage
+                .iter()
+                .all(|&mnemonic| Shanghai::supports_mnemonic(mnemonic))
+        );
+        assert!(opcode_coverage(&[]).is_empty());
+    }
+
+    #[test]
+    fn minimum_fork_of_contracts() {
+        // PUSH1 0x80 PUSH1 0x40 MSTORE PUSH0 CALLDATALOAD PUSH1 0xE0 SHR 0x0C STOP
+        let push0 = [
+            0x60, 0x80, 0x60, 0x40, 0x52, 0x5F, 0x35, 0x60, 0xE0, 0x1C, 0x0C, 0x00,
+        ];
+        assert_eq!(minimum_fork(&push0), HardFork::Shanghai);
+
+        // PUSH1 0x00 CALLDATALOAD PUSH1 0xE0 SHR STOP
+        assert_eq!(
+            minimum_fork(&[0x60, 0x00, 0x35, 0x60, 0xE0, 0x1C, 0x00]),
+            HardFork::Constantinople
+        );
+
+        // PUSH2 0x5E5F (MCOPY and PUSH0 inside push data) STOP
+        assert_eq!(minimum_fork(&[0x61, 0x5E, 0x5F, 0x00]), HardFork::Frontier);
+        assert_eq!(minimum_fork(&[0x0C, 0xEF]), HardFork::Frontier);
+        assert_eq!(minimum_fork(&[]), HardFork::Frontier);
+    }
+
+    #[test]
+    fn gas_trace_sums_to_static_gas() {
+        use crate::{bytecode::Bytecode, disassembler::Disassembler};
+        use upgrades::execution::Latest;
+
+        // PUSH1 0x80 PUSH1 0x40 MSTORE CALLVALUE DUP1 ISZERO PUSH2 0x5B5B 0x0C JUMPI STOP
+        let bytecode = [
+            0x60, 0x80, 0x60, 0x40, 0x52, 0x34, 0x80, 0x15, 0x61, 0x5B, 0x5B, 0x0C, 0x57, 0x00,
+        ];
+        let trace = static_gas_trace(&bytecode);
+
+        assert_eq!(trace.len(), 9);
+        assert_eq!(trace[6], (8, Mnemonic::PUSH2, 3, false));
+        assert_eq!(trace[7], (12, Mnemonic::JUMPI, 10, false));
+        assert_eq!(
+            trace.iter().filter(|(.., dynamic)| *dynamic).count(),
+            1,
+            "only MSTORE has a dynamic cost"
+        );
+
+        let total: u64 = trace.iter().map(|&(_, _, gas, _)| gas).sum();
+        assert_eq!(total, 3 + 3 + 3 + 2 + 3 + 3 + 3 + 10);
+
+        let assembly = Disassembler::<Latest>::default()
+            .disassemble(&Bytecode::from(bytecode.to_vec()))
+            .unwrap();
+        assert_eq!(total, assembly.static_gas());
+        assert!(static_gas_trace(&[]).is_empty());
+    }
+
+    #[test]
+    fn histogram_counts_opcodes() {
+        // PUSH1 0x80 PUSH1 0x40 MSTORE CALLVALUE DUP1 ISZERO 0x0C 0x0C STOP
+        let bytecode = [
+            0x60, 0x80, 0x60, 0x40, 0x52, 0x34, 0x80, 0x15, 0x0C, 0x0C, 0x00,
+        ];
+        let histogram = opcode_histogram(&bytecode);
+
+        assert_eq!(
+            histogram.into_iter().collect::<Vec<_>>(),
+            vec![
+                (OpCode::Known(Mnemonic::STOP), 1),
+                (OpCode::Unknown(0x0C), 2),
+                (OpCode::Known(Mnemonic::ISZERO), 1),
+                (OpCode::Known(Mnemonic::CALLVALUE), 1),
+                (OpCode::Known(Mnemonic::MSTORE), 1),
+                (OpCode::Known(Mnemonic::PUSH1), 2),
+                (OpCode::Known(Mnemonic::DUP1), 1),
+            ]
+        );
+        assert!(opcode_histogram(&[]).is_empty());
+    }
+
+    #[test]
+    fn span_out_of_bounds() {
+        assert_eq!(max_immediate_span(&[], 0), 0);
+        assert_eq!(max_immediate_span(&[0x00], 1), 0);
+        assert_eq!(max_immediate_span(&[0x00], usize::MAX), 0);
+    }
+}
@@ -1,8 +1,11 @@
 //! EVM assembly.
 
-use asm::Instruction;
+use std::fmt::{self, Display};
+
+use asm::{AssemblyInstruction, Instruction};
 
 /// An instruction with a specified position in the bytecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PositionedInstruction {
     /// Position of this instruction in the bytecode.
     pub position: usize,
@@ -11,5 +14,204 @@ pub struct PositionedInstruction {
     pub instruction: Instruction,
 }
 
+impl PositionedInstruction {
+    /// Returns the static gas cost of this instruction and whether it has an additional dynamic
+    /// cost. Unknown instructions halt execution by consuming all gas, so their cost is dynamic.
+    #[must_use]
+    pub fn gas_cost(&self) -> (u64, bool) {
+        self.instruction.mnemonic().map_or((0, true), |mnemonic| {
+            (mnemonic.base_gas_cost(), mnemonic.has_dynamic_gas())
+        })
+    }
+}
+
+impl Display for PositionedInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#06x} {}", self.position, self.instruction)
+    }
+}
+
+/// An instruction with its position and the raw bytes it was disassembled from, see
+/// [`Disassembler::disassemble_annotated`](crate::disassembler::Disassembler::disassemble_annotated).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Annotated<'a> {
+    /// Position of this instruction in the bytecode.
+    pub pc: usize,
+
+    /// The instruction at this position in the bytecode.
+    pub instruction: Instruction,
+
+    /// The original bytes of this instruction, including the opcode and the immediate.
+    pub raw_bytes: &'a [u8],
+}
+
 /// Disassembled EVM bytecode.
-pub struct Assembly(/* Vec<PositionedInstruction> */);
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Assembly(Vec<PositionedInstruction>);
+
+impl Assembly {
+    /// Returns the disassembled instructions.
+    #[must_use]
+    #[inline]
+    pub fn instructions(&self) -> &[PositionedInstruction] {
+        &self.0
+    }
+
+    /// Returns the total static gas cost of all instructions. Dynamic gas costs are not included.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::disassembler::Disassembler;
+    /// # use upgrades::execution::cancun::Cancun;
+    /// // PUSH1 0x01 PUSH1 0x02 ADD STOP
+    /// let assembly = Disassembler::<Cancun>::default()
+    ///     .disassemble_hex("0x600160020100")
+    ///     .unwrap();
+    /// assert_eq!(assembly.static_gas(), 9);
+    /// ```
+    #[must_use]
+    pub fn static_gas(&self) -> u64 {
+        self.0
+            .iter()
+            .map(|instruction| instruction.gas_cost().0)
+            .sum()
+    }
+
+    /// Returns a [`Display`] implementation that annotates every instruction with its static gas
+    /// cost and a running total. Instructions with a dynamic gas cost are marked with a `*`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::disassembler::Disassembler;
+    /// # use upgrades::execution::cancun::Cancun;
+    /// // PUSH1 0x80 PUSH1 0x40 MSTORE
+    /// let assembly = Disassembler::<Cancun>::default()
+    ///     .disassemble_hex("0x6080604052")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     assembly.gas_annotated().to_string(),
+    ///     "0x0000 PUSH1 0x80      3         3\n\
+    ///      0x0002 PUSH1 0x40      3         6\n\
+    ///      0x0004 MSTORE          3*        9*\n\
+    ///      total: 9*\n"
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn gas_annotated(&self) -> GasAnnotated<'_> {
+        GasAnnotated(self)
+    }
+}
+
+impl From<Vec<PositionedInstruction>> for Assembly {
+    fn from(instructions: Vec<PositionedInstruction>) -> Self {
+        Self(instructions)
+    }
+}
+
+impl IntoIterator for Assembly {
+    type Item = PositionedInstruction;
+    type IntoIter = std::vec::IntoIter<PositionedInstruction>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Display for Assembly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for instruction in &self.0 {
+            writeln!(f, "{instruction}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Assembly annotated with static gas costs, see [`Assembly::gas_annotated`].
+pub struct GasAnnotated<'a>(&'a Assembly);
+
+impl Display for GasAnnotated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Returns the dynamic gas marker.
+        fn marker(dynamic: bool) -> &'static str {
+            if dynamic { "*" } else { "" }
+        }
+
+        // Instructions are rendered once, since their width is needed to align the gas columns.
+        let lines: Vec<_> = self
+            .0
+            .instructions()
+            .iter()
+            .map(|instruction| (instruction.to_string(), instruction.gas_cost()))
+            .collect();
+        let width = lines
+            .iter()
+            .map(|(instruction, _)| instruction.len())
+            .max()
+            .unwrap_or_default();
+
+        let mut total = 0;
+        let mut any_dynamic = false;
+
+        for (instruction, (gas, dynamic)) in lines {
+            total += gas;
+            any_dynamic |= dynamic;
+
+            writeln!(
+                f,
+                "{instruction:width$} {gas:>6}{:1} {total:>8}{}",
+                marker(dynamic),
+                marker(any_dynamic),
+            )?;
+        }
+
+        writeln!(f, "total: {total}{}", marker(any_dynamic))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asm::instruction::{Add, Push, SLoad, Stop, Unknown};
+
+    #[test]
+    fn static_gas_sums_instruction_costs() {
+        let assembly = Assembly::from(vec![
+            PositionedInstruction {
+                position: 0,
+                instruction: Instruction::Push1(Push::new([0x1])),
+            },
+            PositionedInstruction {
+                position: 2,
+                instruction: Instruction::SLoad(SLoad),
+            },
+            PositionedInstruction {
+                position: 3,
+                instruction: Instruction::Add(Add),
+            },
+            PositionedInstruction {
+                position: 4,
+                instruction: Instruction::Stop(Stop),
+            },
+        ]);
+
+        assert_eq!(assembly.static_gas(), 3 + 100 + 3);
+        assert!(
+            assembly
+                .gas_annotated()
+                .to_string()
+                .ends_with("total: 106*\n")
+        );
+    }
+
+    #[test]
+    fn unknown_instructions_have_dynamic_gas() {
+        let instruction = PositionedInstruction {
+            position: 0,
+            instruction: Instruction::Unknown(Unknown::new(0xF)),
+        };
+
+        assert_eq!(instruction.gas_cost(), (0, true));
+    }
+}
@@ -0,0 +1,130 @@
+//! EVM bytecode builder.
+
+use asm::{
+    AssemblyInstruction,
+    instruction::{Add, Push, Stop},
+};
+
+use crate::bytecode::Bytecode;
+
+/// A fluent builder that assembles instructions into [`Bytecode`].
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::builder::BytecodeBuilder;
+/// // PUSH1 0x01 PUSH1 0x02 ADD
+/// let bytecode = BytecodeBuilder::new().push1(0x01).push1(0x02).add().build();
+/// assert_eq!(hex::encode(bytecode), "6001600201");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BytecodeBuilder(Vec<u8>);
+
+impl BytecodeBuilder {
+    /// Creates an empty builder.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an instruction.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::builder::BytecodeBuilder;
+    /// # use asm::instruction::{Gas, Instruction, Push};
+    /// let bytes = BytecodeBuilder::new()
+    ///     .instr(Gas)
+    ///     .instr(Instruction::Push2(Push::new([0xA, 0xB])))
+    ///     .into_bytes();
+    /// assert_eq!(bytes, vec![0x5A, 0x61, 0xA, 0xB]);
+    /// ```
+    #[must_use]
+    pub fn instr<I: AssemblyInstruction>(mut self, instruction: I) -> Self {
+        self.0.extend(instruction.assemble());
+        self
+    }
+
+    /// Appends a `PUSH1` instruction.
+    #[must_use]
+    #[inline]
+    pub fn push1(self, value: u8) -> Self {
+        self.instr(Push::new([value]))
+    }
+
+    /// Appends an `ADD` instruction.
+    #[must_use]
+    #[inline]
+    pub fn add(self) -> Self {
+        self.instr(Add)
+    }
+
+    /// Appends a `STOP` instruction.
+    #[must_use]
+    #[inline]
+    pub fn stop(self) -> Self {
+        self.instr(Stop)
+    }
+
+    /// Returns the assembled bytes.
+    #[must_use]
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Returns the assembled [`Bytecode`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::{builder::BytecodeBuilder, bytecode::Bytecode};
+    /// let built = BytecodeBuilder::new().push1(0x01).build();
+    /// assert_eq!(built, Bytecode::from_hex("0x6001").unwrap());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn build(self) -> Bytecode {
+        Bytecode::from(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disassembler::Disassembler;
+    use asm::instruction::{Instruction, MStore};
+    use upgrades::execution::cancun::Cancun;
+
+    #[test]
+    fn built_bytecode_disassembles() {
+        let bytecode = BytecodeBuilder::new()
+            .push1(0x80)
+            .push1(0x40)
+            .instr(MStore)
+            .stop()
+            .build();
+
+        let assembly = Disassembler::<Cancun>::default()
+            .disassemble(&bytecode)
+            .unwrap();
+        let instructions: Vec<_> = assembly
+            .into_iter()
+            .map(|instruction| instruction.instruction)
+            .collect();
+
+        assert_eq!(
+            instructions,
+            vec![
+                Instruction::Push1(Push::new([0x80])),
+                Instruction::Push1(Push::new([0x40])),
+                Instruction::MStore(MStore),
+                Instruction::Stop(Stop),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_builder() {
+        assert!(BytecodeBuilder::new().build().is_empty());
+    }
+}
@@ -1,9 +1,188 @@
 //! EVM bytecode.
 
+use std::{
+    fmt::{self, Display},
+    ops::Index,
+    slice::SliceIndex,
+};
+
+use asm::{AssemblyInstruction, DisassemblyError, Instruction, OpCode};
+
+use crate::source::BytecodeSource;
+
 /// EVM bytecode.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub struct Bytecode(Vec<u8>);
 
+impl Bytecode {
+    /// Decodes bytecode from a hex string with an optional `0x` prefix.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::bytecode::Bytecode;
+    /// let bytecode = Bytecode::from_hex("0x6001").unwrap();
+    /// assert_eq!(bytecode, Bytecode::from(vec![0x60, 0x01]));
+    /// assert_eq!(bytecode.to_string(), "0x6001");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the string is not valid hex.
+    pub fn from_hex<T: AsRef<str>>(hex: T) -> Result<Self, hex::FromHexError> {
+        BytecodeSource::extract(hex.as_ref())
+    }
+
+    /// Assembles instructions into bytecode. Unknown instructions are kept as their original byte,
+    /// so they survive disassembling bytecode and assembling the instructions again.
+    ///
+    /// A `PUSHx` instruction always assembles to its full immediate, so bytecode ending in a
+    /// truncated `PUSHx` is not reproduced. Use
+    /// [`Disassembler::disassemble_annotated`](crate::disassembler::Disassembler::disassemble_annotated)
+    /// to keep the original bytes of every instruction.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::bytecode::Bytecode;
+    /// # use asm::instruction::*;
+    /// let bytecode = Bytecode::from_instructions(&[
+    ///     Instruction::Push1(Push::new([0x01])),
+    ///     Instruction::Unknown(Unknown::new(0x0C)),
+    /// ]);
+    /// assert_eq!(bytecode.as_bytes(), &[0x60, 0x01, 0x0C]);
+    /// ```
+    #[must_use]
+    pub fn from_instructions(instructions: &[Instruction]) -> Self {
+        Self(
+            instructions
+                .iter()
+                .flat_map(|instruction| instruction.assemble())
+                .collect(),
+        )
+    }
+
+    /// Returns the length of the bytecode in bytes.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::bytecode::Bytecode;
+    /// assert_eq!(Bytecode::from(vec![0x60, 0x01]).len(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns [`true`] if the bytecode contains no bytes.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::bytecode::Bytecode;
+    /// assert!(Bytecode::from(vec![]).is_empty());
+    /// assert!(!Bytecode::from(vec![0x00]).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the raw bytes of the bytecode.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::bytecode::Bytecode;
+    /// assert_eq!(Bytecode::from(vec![0x60, 0x01]).as_bytes(), &[0x60, 0x01]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the byte at the program counter `pc` or [`None`] if `pc` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::bytecode::Bytecode;
+    /// let bytecode = Bytecode::from(vec![0x60, 0x01]);
+    /// assert_eq!(bytecode.get(1), Some(0x01));
+    /// assert_eq!(bytecode.get(2), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, pc: usize) -> Option<u8> {
+        self.0.get(pc).copied()
+    }
+
+    /// Returns the [`OpCode`] at the program counter `pc` if it is the start of an instruction.
+    /// Returns [`None`] if `pc` is out of bounds or falls within the immediate of a `PUSH`
+    /// instruction.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::bytecode::Bytecode;
+    /// # use asm::{Mnemonic, OpCode};
+    /// // PUSH1 0x5B JUMPDEST
+    /// let bytecode = Bytecode::from(vec![0x60, 0x5B, 0x5B]);
+    ///
+    /// assert_eq!(bytecode.opcode_at(0), Some(OpCode::Known(Mnemonic::PUSH1)));
+    /// assert_eq!(bytecode.opcode_at(1), None);
+    /// assert_eq!(bytecode.opcode_at(2), Some(OpCode::Known(Mnemonic::JUMPDEST)));
+    /// ```
+    #[must_use]
+    pub fn opcode_at(&self, pc: usize) -> Option<OpCode> {
+        let mut position = 0;
+
+        while position < pc {
+            let opcode = OpCode::from_byte(self.get(position)?);
+            position += 1 + usize::from(opcode.push_size().unwrap_or(0));
+        }
+
+        (position == pc)
+            .then(|| self.get(pc))
+            .flatten()
+            .map(OpCode::from_byte)
+    }
+
+    /// Returns an iterator over the instructions of the bytecode and their positions. The iterator
+    /// ends after the first instruction that fails to disassemble.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::bytecode::Bytecode;
+    /// # use asm::instruction::*;
+    /// // PUSH1 0x01 GAS PUSH2 0x01
+    /// let bytecode = Bytecode::from(vec![0x60, 0x01, 0x5A, 0x61, 0x01]);
+    /// let mut instructions = bytecode.instructions();
+    ///
+    /// assert_eq!(instructions.next(), Some(Ok((0, Instruction::Push1(Push::new([0x01]))))));
+    /// assert_eq!(instructions.next(), Some(Ok((2, Instruction::Gas(Gas)))));
+    /// assert!(matches!(instructions.next(), Some(Err(_))));
+    /// assert_eq!(instructions.next(), None);
+    /// ```
+    pub fn instructions(
+        &self,
+    ) -> impl Iterator<Item = Result<(usize, Instruction), DisassemblyError>> + '_ {
+        let mut pc = 0;
+
+        std::iter::from_fn(move || {
+            let bytes = self.0.get(pc..).filter(|bytes| !bytes.is_empty())?;
+            let position = pc;
+
+            match Instruction::disassemble(bytes) {
+                Ok(instruction) => {
+                    pc += usize::from(instruction.size());
+                    Some(Ok((position, instruction)))
+                }
+                Err(error) => {
+                    pc = self.0.len();
+                    Some(Err(error))
+                }
+            }
+        })
+    }
+}
+
 impl From<Vec<u8>> for Bytecode {
     fn from(bytes: Vec<u8>) -> Self {
         Self(bytes)
@@ -21,3 +200,163 @@ impl AsRef<[u8]> for Bytecode {
         &self.0
     }
 }
+
+impl Display for Bytecode {
+    /// Formats the bytecode as a lowercase hex string prefixed with `0x`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(&self.0))
+    }
+}
+
+impl<I: SliceIndex<[u8]>> Index<I> for Bytecode {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asm::{
+        Mnemonic,
+        instruction::{MStore, Push},
+    };
+
+    #[test]
+    fn empty_bytecode() {
+        let bytecode = Bytecode::from(vec![]);
+
+        assert_eq!(bytecode.len(), 0);
+        assert!(bytecode.is_empty());
+        assert!(bytecode.as_bytes().is_empty());
+        assert_eq!(bytecode.get(0), None);
+        assert!(bytecode[..].is_empty());
+    }
+
+    #[test]
+    fn bytecode_accessors() {
+        let bytecode = Bytecode::from(vec![0x60, 0x80, 0x60, 0x40, 0x52]);
+
+        assert_eq!(bytecode.len(), 5);
+        assert!(!bytecode.is_empty());
+        assert_eq!(bytecode.as_bytes(), &[0x60, 0x80, 0x60, 0x40, 0x52]);
+        assert_eq!(bytecode.get(4), Some(0x52));
+        assert_eq!(bytecode.get(5), None);
+
+        assert_eq!(bytecode[1], 0x80);
+        assert_eq!(&bytecode[1..3], &[0x80, 0x60]);
+        assert_eq!(&bytecode[3..], &[0x40, 0x52]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn bytecode_index_out_of_bounds() {
+        let bytecode = Bytecode::from(vec![0x00]);
+        let _ = bytecode[1];
+    }
+
+    #[test]
+    fn bytecode_hex() {
+        let bytecode = Bytecode::from_hex("0x6080604052").unwrap();
+
+        assert_eq!(bytecode, Bytecode::from(vec![0x60, 0x80, 0x60, 0x40, 0x52]));
+        assert_eq!(bytecode.to_string(), "0x6080604052");
+        assert_eq!(Bytecode::from_hex("6080").unwrap().to_string(), "0x6080");
+        assert_eq!(Bytecode::default().to_string(), "0x");
+        assert!(Bytecode::from_hex("0x608").is_err());
+
+        assert_ne!(bytecode, Bytecode::from_hex("0x6080").unwrap());
+    }
+
+    #[test]
+    fn bytecode_from_instructions_round_trips() {
+        use crate::disassembler::{DasmError, Disassembler};
+        use upgrades::execution::Latest;
+
+        let dasm = Disassembler::<Latest>::default();
+        let mut state: u32 = 0x1234_5678;
+        let reassemble = |assembly: crate::assembly::Assembly| {
+            let instructions: Vec<_> = assembly
+                .into_iter()
+                .map(|positioned| positioned.instruction)
+                .collect();
+            Bytecode::from_instructions(&instructions)
+        };
+
+        for len in 0..200 {
+            // Deterministic pseudo-random bytes.
+            let bytes: Vec<u8> = (0..len)
+                .map(|_| {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                    state.to_be_bytes()[1]
+                })
+                .collect();
+            let bytecode = Bytecode::from(bytes);
+
+            match dasm.disassemble(&bytecode) {
+                Ok(assembly) => assert_eq!(reassemble(assembly), bytecode),
+                // Only a trailing truncated `PUSHx` fails to disassemble, everything before it
+                // round trips.
+                Err(DasmError::Instruction { position, .. }) => {
+                    let push_size = OpCode::from_byte(bytecode[position]).push_size().unwrap();
+                    assert!(position + 1 + usize::from(push_size) > bytecode.len());
+
+                    let prefix = Bytecode::from(&bytecode[..position]);
+                    assert_eq!(reassemble(dasm.disassemble(&prefix).unwrap()), prefix);
+                }
+                Err(error) => panic!("unexpected error: {error}"),
+            }
+        }
+
+        assert!(Bytecode::from_instructions(&[]).is_empty());
+    }
+
+    #[test]
+    fn bytecode_opcode_at() {
+        // PUSH2 0x5B5B JUMPDEST PUSH32 <truncated>
+        let bytecode = Bytecode::from(vec![0x61, 0x5B, 0x5B, 0x5B, 0x7F, 0x00]);
+
+        assert_eq!(bytecode.opcode_at(0), Some(OpCode::Known(Mnemonic::PUSH2)));
+        assert_eq!(bytecode.opcode_at(1), None);
+        assert_eq!(bytecode.opcode_at(2), None);
+        assert_eq!(
+            bytecode.opcode_at(3),
+            Some(OpCode::Known(Mnemonic::JUMPDEST))
+        );
+        assert_eq!(bytecode.opcode_at(4), Some(OpCode::Known(Mnemonic::PUSH32)));
+        assert_eq!(bytecode.opcode_at(5), None);
+        assert_eq!(bytecode.opcode_at(6), None);
+        assert_eq!(bytecode.opcode_at(100), None);
+
+        assert_eq!(
+            Bytecode::from(vec![0x0C]).opcode_at(0),
+            Some(OpCode::Unknown(0x0C))
+        );
+        assert_eq!(Bytecode::default().opcode_at(0), None);
+    }
+
+    #[test]
+    fn bytecode_instructions() {
+        let bytecode = Bytecode::from(vec![0x60, 0x80, 0x60, 0x40, 0x52]);
+
+        assert_eq!(
+            bytecode.instructions().collect::<Result<Vec<_>, _>>(),
+            Ok(vec![
+                (0, Instruction::Push1(Push::new([0x80]))),
+                (2, Instruction::Push1(Push::new([0x40]))),
+                (4, Instruction::MStore(MStore)),
+            ])
+        );
+
+        assert_eq!(Bytecode::from(vec![]).instructions().next(), None);
+
+        let truncated = Bytecode::from(vec![0x52, 0x7F, 0x01]);
+        let results: Vec<_> = truncated.instructions().collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0], Ok((0, Instruction::MStore(MStore))));
+        assert!(results[1].is_err());
+    }
+}
@@ -2,21 +2,131 @@
 
 use std::marker::PhantomData;
 
+use asm::{AssemblyInstruction, DisassemblyError, Instruction, instruction::Unknown};
 use thiserror::Error;
-use upgrades::execution::ExecutionUpgrade;
+use upgrades::{execution::ExecutionUpgrade, hardfork::HardFork};
 
-use crate::{assembly::Assembly, bytecode::Bytecode, source::BytecodeSource};
+use crate::{
+    assembly::{Annotated, Assembly, PositionedInstruction},
+    bytecode::Bytecode,
+    source::BytecodeSource,
+};
 
 /// EVM disassembler.
-#[derive(Default)]
 pub struct Disassembler<E: ExecutionUpgrade> {
+    /// Disassembly options.
+    options: DisassembleOptions,
+
     /// Marker for storing the `ExecutionUpgrade` generic.
     _marker: PhantomData<E>,
 }
 
+impl<E: ExecutionUpgrade> Default for Disassembler<E> {
+    fn default() -> Self {
+        Self::with_options(DisassembleOptions::default())
+    }
+}
+
+/// Options that configure a [`Disassembler`], see [`Disassembler::builder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct DisassembleOptions {
+    /// Stop with an error at the first unknown opcode instead of emitting
+    /// [`Instruction::Unknown`].
+    pub stop_on_unknown: bool,
+
+    /// Pad a `PUSHx` immediate truncated by the end of the bytecode with zeros, like the EVM does,
+    /// instead of failing with an error.
+    pub pad_truncated: bool,
+
+    /// Ignore the CBOR encoded metadata that the Solidity and Vyper compilers append to the
+    /// bytecode, so it is not disassembled into garbage instructions. It is kept by
+    /// [`Disassembler::disassemble_annotated`], which reproduces the bytecode exactly.
+    pub skip_metadata: bool,
+
+    /// Disassemble opcodes that are not supported by this hard fork as
+    /// [`Instruction::Unknown`].
+    pub fork: Option<HardFork>,
+}
+
+/// A builder for a [`Disassembler`], see [`Disassembler::builder`].
+pub struct DisassemblerBuilder<E: ExecutionUpgrade> {
+    /// Options of the built disassembler.
+    options: DisassembleOptions,
+
+    /// Marker for storing the `ExecutionUpgrade` generic.
+    _marker: PhantomData<E>,
+}
+
+impl<E: ExecutionUpgrade> DisassemblerBuilder<E> {
+    /// Sets [`DisassembleOptions::stop_on_unknown`].
+    #[must_use]
+    #[inline]
+    pub const fn stop_on_unknown(mut self, stop_on_unknown: bool) -> Self {
+        self.options.stop_on_unknown = stop_on_unknown;
+        self
+    }
+
+    /// Sets [`DisassembleOptions::pad_truncated`].
+    #[must_use]
+    #[inline]
+    pub const fn pad_truncated(mut self, pad_truncated: bool) -> Self {
+        self.options.pad_truncated = pad_truncated;
+        self
+    }
+
+    /// Sets [`DisassembleOptions::skip_metadata`].
+    #[must_use]
+    #[inline]
+    pub const fn skip_metadata(mut self, skip_metadata: bool) -> Self {
+        self.options.skip_metadata = skip_metadata;
+        self
+    }
+
+    /// Sets [`DisassembleOptions::fork`].
+    #[must_use]
+    #[inline]
+    pub const fn fork(mut self, fork: HardFork) -> Self {
+        self.options.fork = Some(fork);
+        self
+    }
+
+    /// Builds the configured disassembler.
+    #[must_use]
+    #[inline]
+    pub const fn build(self) -> Disassembler<E> {
+        Disassembler::with_options(self.options)
+    }
+}
+
 /// Errors that can happen when disassembling bytecode.
 #[derive(Debug, Error)]
-pub enum DasmError {}
+pub enum DasmError {
+    /// An instruction could not be disassembled.
+    #[error("failed to disassemble instruction at position {position}: {source}")]
+    Instruction {
+        /// Position of the instruction in the bytecode.
+        position: usize,
+        /// The underlying instruction disassembly error.
+        source: DisassemblyError,
+    },
+
+    /// An unknown opcode was encountered while [`DisassembleOptions::stop_on_unknown`] is set.
+    #[error("unknown opcode {opcode:#04x} at position {position}")]
+    UnknownOpCode {
+        /// Position of the opcode in the bytecode.
+        position: usize,
+        /// The unknown opcode.
+        opcode: u8,
+    },
+
+    /// The output buffer can not hold all disassembled instructions.
+    #[error("output buffer with capacity {capacity} is too small")]
+    BufferTooSmall {
+        /// Capacity of the output buffer.
+        capacity: usize,
+    },
+}
 
 /// An error that can happen when disassembling from source.
 #[derive(Debug, Error)]
@@ -31,12 +141,310 @@ pub enum SourceDasmError<E> {
 }
 
 impl<E: ExecutionUpgrade> Disassembler<E> {
+    /// Creates a disassembler with the options of another disassembler, which are configured
+    /// with [`Disassembler::builder`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::disassembler::Disassembler;
+    /// # use upgrades::execution::{berlin::Berlin, cancun::Cancun};
+    /// let options = Disassembler::<Cancun>::builder()
+    ///     .stop_on_unknown(true)
+    ///     .build()
+    ///     .options();
+    /// let dasm = Disassembler::<Berlin>::with_options(options);
+    ///
+    /// assert!(dasm.disassemble_hex("0x5a0f").is_err());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn with_options(options: DisassembleOptions) -> Self {
+        Self {
+            options,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a builder for a disassembler with the default options.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::disassembler::Disassembler;
+    /// # use upgrades::{execution::Latest, hardfork::HardFork};
+    /// let dasm = Disassembler::<Latest>::builder()
+    ///     .pad_truncated(true)
+    ///     .skip_metadata(true)
+    ///     .fork(HardFork::Cancun)
+    ///     .build();
+    ///
+    /// assert!(dasm.options().pad_truncated);
+    /// assert_eq!(dasm.options().fork, Some(HardFork::Cancun));
+    ///
+    /// // GAS PUSH2 0x01 (truncated)
+    /// let assembly = dasm.disassemble_hex("0x5a6101").unwrap();
+    /// assert_eq!(assembly.to_string(), "0x0000 GAS\n0x0001 PUSH2 0x0100\n");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn builder() -> DisassemblerBuilder<E> {
+        DisassemblerBuilder {
+            options: DisassembleOptions {
+                stop_on_unknown: false,
+                pad_truncated: false,
+                skip_metadata: false,
+                fork: None,
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the options of this disassembler.
+    #[must_use]
+    #[inline]
+    pub const fn options(&self) -> DisassembleOptions {
+        self.options
+    }
+
     /// Disassemble EVM bytecode into an instruction list.
     ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::{bytecode::Bytecode, disassembler::Disassembler};
+    /// # use upgrades::execution::cancun::Cancun;
+    /// # use asm::instruction::*;
+    /// let bytecode = Bytecode::from(vec![0x60, 0x01, 0x5A]);
+    /// let assembly = Disassembler::<Cancun>::default().disassemble(&bytecode).unwrap();
+    /// let instructions = assembly.instructions();
+    ///
+    /// assert_eq!(instructions[0].position, 0);
+    /// assert_eq!(instructions[0].instruction, Instruction::Push1(Push::new([0x01])));
+    /// assert_eq!(instructions[1].position, 2);
+    /// assert_eq!(instructions[1].instruction, Instruction::Gas(Gas));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if an instruction could not be disassembled (e.g. a `PUSHx` instruction is
+    /// truncated by the end of the bytecode) or if an unknown opcode was encountered while
+    /// [`DisassembleOptions::stop_on_unknown`] is set.
+    pub fn disassemble(&self, bytecode: &Bytecode) -> Result<Assembly, DasmError> {
+        let bytes = self.code(bytecode.as_ref());
+        let mut instructions = Vec::new();
+        let mut position = 0;
+
+        while position < bytes.len() {
+            let instruction = self.decode(bytes, position)?;
+
+            instructions.push(PositionedInstruction {
+                position,
+                instruction,
+            });
+
+            position += usize::from(instruction.size());
+        }
+
+        Ok(Assembly::from(instructions))
+    }
+
+    /// Disassembles EVM bytecode into a caller provided buffer without allocating. Returns the
+    /// number of instructions written to the buffer.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::disassembler::Disassembler;
+    /// # use upgrades::execution::cancun::Cancun;
+    /// # use asm::instruction::*;
+    /// let mut buffer = [Instruction::Stop(Stop); 4];
+    /// let count = Disassembler::<Cancun>::default()
+    ///     .disassemble_into(&[0x60, 0x01, 0x5A], &mut buffer)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     &buffer[..count],
+    ///     &[Instruction::Push1(Push::new([0x01])), Instruction::Gas(Gas)]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the buffer can not hold all instructions or if disassembly failed (see
+    /// [`Disassembler::disassemble`]).
+    pub fn disassemble_into(
+        &self,
+        bytes: &[u8],
+        buffer: &mut [Instruction],
+    ) -> Result<usize, DasmError> {
+        let bytes = self.code(bytes);
+        let capacity = buffer.len();
+        let mut count = 0;
+        let mut position = 0;
+
+        while position < bytes.len() {
+            let instruction = self.decode(bytes, position)?;
+
+            let slot = buffer
+                .get_mut(count)
+                .ok_or(DasmError::BufferTooSmall { capacity })?;
+            *slot = instruction;
+
+            count += 1;
+            position += usize::from(instruction.size());
+        }
+
+        Ok(count)
+    }
+
+    /// Disassembles bytecode and flags every instruction with whether it is valid under the
+    /// execution upgrade `E`, see [`ExecutionUpgrade::supports_opcode`]. Returns a list of
+    /// `(position, instruction, valid)` tuples.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::{bytecode::Bytecode, disassembler::Disassembler};
+    /// # use upgrades::execution::berlin::Berlin;
+    /// # use asm::instruction::*;
+    /// // PUSH0 STOP
+    /// let bytecode = Bytecode::from(vec![0x5F, 0x00]);
+    /// let checked = Disassembler::<Berlin>::default().disassemble_checked(&bytecode).unwrap();
+    ///
+    /// assert_eq!(
+    ///     checked,
+    ///     [
+    ///         (0, Instruction::Push0(Push::new([])), false),
+    ///         (1, Instruction::Stop(Stop), true),
+    ///     ]
+    /// );
+    /// ```
+    ///
     /// # Errors
-    /// TODO
-    pub fn disassemble(&self, _bytecode: &Bytecode) -> Result<Assembly, DasmError> {
-        todo!()
+    /// Returns an error if disassembly failed, see [`Disassembler::disassemble`].
+    pub fn disassemble_checked(
+        &self,
+        bytecode: &Bytecode,
+    ) -> Result<Vec<(usize, Instruction, bool)>, DasmError> {
+        Ok(self
+            .disassemble(bytecode)?
+            .into_iter()
+            .map(
+                |PositionedInstruction {
+                     position,
+                     instruction,
+                 }| {
+                    (
+                        position,
+                        instruction,
+                        E::supports_opcode(instruction.opcode()),
+                    )
+                },
+            )
+            .collect())
+    }
+
+    /// Disassembles bytecode while keeping the original bytes of every instruction, so that the
+    /// bytecode can be reproduced exactly by concatenating them.
+    ///
+    /// A `PUSHx` instruction truncated by the end of the bytecode is not an error: its immediate
+    /// is padded with zeros, like the EVM does, while its raw bytes only include the bytes that
+    /// are present. For the same reason [`DisassembleOptions::skip_metadata`] is ignored and the
+    /// metadata is disassembled like the rest of the bytecode.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::{bytecode::Bytecode, disassembler::Disassembler};
+    /// # use upgrades::execution::cancun::Cancun;
+    /// # use asm::instruction::*;
+    /// // PUSH1 0x01 <unknown 0x0C> PUSH2 0x02 (truncated)
+    /// let bytecode = Bytecode::from(vec![0x60, 0x01, 0x0C, 0x61, 0x02]);
+    /// let annotated = Disassembler::<Cancun>::default()
+    ///     .disassemble_annotated(&bytecode)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(annotated[0].pc, 0);
+    /// assert_eq!(annotated[0].instruction, Instruction::Push1(Push::new([0x01])));
+    /// assert_eq!(annotated[0].raw_bytes, &[0x60, 0x01]);
+    /// assert_eq!(annotated[1].raw_bytes, &[0x0C]);
+    /// assert_eq!(annotated[2].instruction, Instruction::Push2(Push::new([0x02, 0x00])));
+    /// assert_eq!(annotated[2].raw_bytes, &[0x61, 0x02]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if an unknown opcode was encountered while
+    /// [`DisassembleOptions::stop_on_unknown`] is set.
+    pub fn disassemble_annotated<'a>(
+        &self,
+        bytecode: &'a Bytecode,
+    ) -> Result<Vec<Annotated<'a>>, DasmError> {
+        let bytes = bytecode.as_bytes();
+        let mut annotated = Vec::new();
+        let mut position = 0;
+
+        while position < bytes.len() {
+            let instruction = self.decode_padded(bytes, position, true)?;
+            let end = bytes.len().min(position + usize::from(instruction.size()));
+
+            annotated.push(Annotated {
+                pc: position,
+                instruction,
+                raw_bytes: &bytes[position..end],
+            });
+
+            position = end;
+        }
+
+        Ok(annotated)
+    }
+
+    /// Returns the part of the bytecode that is disassembled, which excludes the metadata if
+    /// [`DisassembleOptions::skip_metadata`] is set.
+    fn code<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        if self.options.skip_metadata {
+            &bytes[..bytes.len() - metadata_len(bytes)]
+        } else {
+            bytes
+        }
+    }
+
+    /// Decodes the instruction at `position`.
+    fn decode(&self, bytes: &[u8], position: usize) -> Result<Instruction, DasmError> {
+        self.decode_padded(bytes, position, self.options.pad_truncated)
+    }
+
+    /// Decodes the instruction at `position`, padding a truncated immediate with zeros if `pad` is
+    /// set.
+    fn decode_padded(
+        &self,
+        bytes: &[u8],
+        position: usize,
+        pad: bool,
+    ) -> Result<Instruction, DasmError> {
+        let remaining = &bytes[position..];
+
+        let instruction = match Instruction::disassemble(remaining) {
+            Err(DisassemblyError::UnexpectedLength { expected, .. })
+                if pad && !remaining.is_empty() =>
+            {
+                // The longest instruction is `PUSH32` with its 32 byte immediate.
+                let mut padded = [0; 33];
+                padded[..remaining.len()].copy_from_slice(remaining);
+                Instruction::disassemble(&padded[..expected])
+            }
+            result => result,
+        }
+        .map_err(|source| DasmError::Instruction { position, source })?;
+
+        let instruction = match (self.options.fork, instruction.mnemonic()) {
+            (Some(fork), Some(mnemonic)) if !fork.supports_mnemonic(mnemonic) => {
+                Instruction::Unknown(Unknown::new(mnemonic.into_byte()))
+            }
+            _ => instruction,
+        };
+
+        if self.options.stop_on_unknown && instruction.mnemonic().is_none() {
+            return Err(DasmError::UnknownOpCode {
+                position,
+                opcode: instruction.opcode().into_byte(),
+            });
+        }
+
+        Ok(instruction)
     }
 
     /// Disassembles any source that provides [`Bytecode`] into EVM assembly.
@@ -73,3 +481,410 @@ impl<E: ExecutionUpgrade> Disassembler<E> {
         self.disassemble_from_source(bytes.as_ref())
     }
 }
+
+/// CBOR encoded keys of the metadata maps appended by the Solidity and Vyper compilers.
+const METADATA_KEYS: [&[u8]; 5] = [
+    b"\x64ipfs",
+    b"\x65bzzr0",
+    b"\x65bzzr1",
+    b"\x64solc",
+    b"\x65vyper",
+];
+
+/// Returns the length of the CBOR encoded compiler metadata at the end of the bytecode,
+/// including its two byte length suffix, or `0` if there is none.
+///
+/// The metadata is recognized by the big-endian length in the last two bytes, which has to point
+/// at the start of a non-empty CBOR map that contains one of the [`METADATA_KEYS`]. Checking the
+/// keys keeps code that merely ends in a byte like `LOG1` (`0xA1`) from being mistaken for
+/// metadata.
+fn metadata_len(bytes: &[u8]) -> usize {
+    let [.., high, low] = *bytes else {
+        return 0;
+    };
+    let len = usize::from(u16::from_be_bytes([high, low])) + 2;
+
+    let Some(metadata) = bytes
+        .len()
+        .checked_sub(len)
+        .map(|start| &bytes[start..bytes.len() - 2])
+    else {
+        return 0;
+    };
+
+    let is_map = matches!(metadata.first(), Some(0xA1..=0xBF));
+    let has_known_key = METADATA_KEYS
+        .iter()
+        .any(|key| metadata.windows(key.len()).any(|window| window == *key));
+
+    if is_map && has_known_key { len } else { 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asm::instruction::{Add, Push, Stop, Unknown};
+    use asm::testing::instr;
+    use upgrades::execution::{berlin::Berlin, cancun::Cancun, shanghai::Shanghai};
+
+    #[test]
+    fn it_disassembles_bytecode() {
+        let dasm = Disassembler::<Cancun>::default();
+
+        // PUSH1 0x80 PUSH1 0x40 MSTORE INVALID
+        let assembly = dasm.disassemble_hex("0x6080604052fe").unwrap();
+        assert_eq!(
+            assembly.to_string(),
+            "0x0000 PUSH1 0x80\n0x0002 PUSH1 0x40\n0x0004 MSTORE\n0x0005 INVALID\n"
+        );
+
+        assert!(
+            dasm.disassemble_bytes([])
+                .unwrap()
+                .instructions()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn it_fails_on_truncated_instructions() {
+        let dasm = Disassembler::<Cancun>::default();
+
+        assert!(matches!(
+            dasm.disassemble_bytes([0x5A, 0x61, 0x01]).unwrap_err(),
+            SourceDasmError::Dasm(DasmError::Instruction { position: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn copy_operands_are_not_immediates() {
+        use asm::{AssemblyInstruction, Mnemonic};
+
+        let dasm = Disassembler::<Cancun>::default();
+
+        // The *COPY opcodes take all their operands from the stack, so the bytes following them
+        // must be decoded as instructions of their own.
+        // CALLDATACOPY ADD CODECOPY ADD EXTCODECOPY ADD RETURNDATACOPY ADD MCOPY ADD
+        let assembly = dasm.disassemble_hex("0x370139013c013e015e01").unwrap();
+        assert_eq!(
+            assembly.to_string(),
+            "0x0000 CALLDATACOPY\n0x0001 ADD\n0x0002 CODECOPY\n0x0003 ADD\n0x0004 EXTCODECOPY\n\
+             0x0005 ADD\n0x0006 RETURNDATACOPY\n0x0007 ADD\n0x0008 MCOPY\n0x0009 ADD\n"
+        );
+
+        for positioned in assembly.instructions() {
+            assert_eq!(positioned.instruction.immediate_size(), 0);
+            assert_eq!(positioned.instruction.size(), 1);
+        }
+
+        // A trailing RETURNDATACOPY is complete on its own and is not a truncated instruction.
+        assert!(dasm.disassemble_bytes([0x3E]).is_ok());
+
+        for mnemonic in Mnemonic::VARIANTS
+            .iter()
+            .filter(|mnemonic| mnemonic.to_string().ends_with("COPY"))
+        {
+            assert_eq!(mnemonic.push_size(), None, "{mnemonic}");
+        }
+    }
+
+    #[test]
+    fn it_disassembles_into_a_buffer() {
+        let dasm = Disassembler::<Cancun>::default();
+        let mut buffer = [Instruction::Stop(Stop); 3];
+
+        // PUSH1 0x80 PUSH1 0x40 MSTORE
+        let bytes = [0x60, 0x80, 0x60, 0x40, 0x52];
+        assert_eq!(dasm.disassemble_into(&bytes, &mut buffer).unwrap(), 3);
+        assert_eq!(
+            buffer,
+            [
+                instr("PUSH1", "80"),
+                instr("PUSH1", "40"),
+                instr("MSTORE", "")
+            ]
+        );
+
+        assert_eq!(dasm.disassemble_into(&[], &mut buffer).unwrap(), 0);
+        assert_eq!(
+            dasm.disassemble_into(&[0x5A], &mut [])
+                .unwrap_err()
+                .to_string(),
+            "output buffer with capacity 0 is too small"
+        );
+    }
+
+    #[test]
+    fn it_fails_on_undersized_buffers() {
+        let dasm = Disassembler::<Cancun>::default();
+        let mut buffer = [Instruction::Stop(Stop); 2];
+
+        assert!(matches!(
+            dasm.disassemble_into(&[0x60, 0x80, 0x60, 0x40, 0x52], &mut buffer),
+            Err(DasmError::BufferTooSmall { capacity: 2 })
+        ));
+        assert!(matches!(
+            dasm.disassemble_into(&[0x5A, 0x61, 0x01], &mut buffer),
+            Err(DasmError::Instruction { position: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn it_flags_instructions_invalid_for_the_upgrade() {
+        // PUSH0 PUSH1 0x01 ADD
+        let bytecode = Bytecode::from(vec![0x5F, 0x60, 0x01, 0x01]);
+        let validity = |checked: Vec<(usize, Instruction, bool)>| {
+            checked
+                .into_iter()
+                .map(|(position, _, valid)| (position, valid))
+                .collect::<Vec<_>>()
+        };
+
+        let berlin = Disassembler::<Berlin>::default()
+            .disassemble_checked(&bytecode)
+            .unwrap();
+        assert_eq!(berlin[0].1, instr("PUSH0", ""));
+        assert_eq!(validity(berlin), [(0, false), (1, true), (3, true)]);
+
+        let shanghai = Disassembler::<Shanghai>::default()
+            .disassemble_checked(&bytecode)
+            .unwrap();
+        assert_eq!(validity(shanghai), [(0, true), (1, true), (3, true)]);
+    }
+
+    #[test]
+    fn lenient_mode_emits_unknown_instructions() {
+        let dasm = Disassembler::<Cancun>::default();
+        assert!(!dasm.options().stop_on_unknown);
+
+        // GAS 0x0F STOP
+        let assembly = dasm.disassemble_hex("0x5a0f00").unwrap();
+        assert_eq!(
+            assembly.instructions()[1].instruction,
+            Instruction::Unknown(Unknown::new(0x0F))
+        );
+        assert_eq!(assembly.instructions().len(), 3);
+    }
+
+    #[test]
+    fn strict_mode_stops_on_unknown_instructions() {
+        let dasm = Disassembler::<Cancun>::builder()
+            .stop_on_unknown(true)
+            .build();
+
+        let error = dasm.disassemble_hex("0x5a0f00").unwrap_err();
+        assert!(matches!(
+            error,
+            SourceDasmError::Dasm(DasmError::UnknownOpCode {
+                position: 1,
+                opcode: 0x0F
+            })
+        ));
+        assert_eq!(
+            error.to_string(),
+            "disassembly failed: unknown opcode 0x0f at position 1"
+        );
+
+        assert!(dasm.disassemble_hex("0x5a00").is_ok());
+    }
+
+    #[test]
+    fn builder_configures_options() {
+        let dasm = Disassembler::<Cancun>::builder().build();
+        assert_eq!(dasm.options(), DisassembleOptions::default());
+
+        let dasm = Disassembler::<Cancun>::builder()
+            .stop_on_unknown(true)
+            .pad_truncated(true)
+            .skip_metadata(true)
+            .fork(HardFork::Berlin)
+            .build();
+        assert_eq!(
+            dasm.options(),
+            DisassembleOptions {
+                stop_on_unknown: true,
+                pad_truncated: true,
+                skip_metadata: true,
+                fork: Some(HardFork::Berlin),
+            }
+        );
+    }
+
+    #[test]
+    fn truncated_immediates_are_padded() {
+        let bytecode = Bytecode::from(vec![0x5A, 0x62, 0x01]);
+        let dasm = Disassembler::<Cancun>::builder()
+            .pad_truncated(true)
+            .build();
+
+        assert!(
+            Disassembler::<Cancun>::default()
+                .disassemble(&bytecode)
+                .is_err()
+        );
+        assert_eq!(
+            dasm.disassemble(&bytecode).unwrap().to_string(),
+            "0x0000 GAS\n0x0001 PUSH3 0x010000\n"
+        );
+
+        let annotated = dasm.disassemble_annotated(&bytecode).unwrap();
+        assert_eq!(annotated[1].raw_bytes, &[0x62, 0x01]);
+
+        let mut buffer = [Instruction::Stop(Stop); 2];
+        assert_eq!(dasm.disassemble_into(&[0x7F], &mut buffer).unwrap(), 1);
+        assert_eq!(buffer[0], Instruction::Push32(Push::new([0; 32])));
+    }
+
+    #[test]
+    fn metadata_is_skipped() {
+        // PUSH1 0x80 STOP {"solc": 0x00081c} <length 0x000a>
+        let bytecode = "0x608000a164736f6c634300081c000a";
+
+        let dasm = Disassembler::<Cancun>::builder()
+            .skip_metadata(true)
+            .build();
+        assert_eq!(
+            dasm.disassemble_hex(bytecode).unwrap().to_string(),
+            "0x0000 PUSH1 0x80\n0x0002 STOP\n"
+        );
+        assert_eq!(
+            Disassembler::<Cancun>::default()
+                .disassemble_hex(bytecode)
+                .unwrap()
+                .instructions()
+                .len(),
+            9
+        );
+
+        // Bytecode without metadata is left as is.
+        assert_eq!(metadata_len(&[]), 0);
+        assert_eq!(metadata_len(&[0x00]), 0);
+        assert_eq!(metadata_len(&[0x60, 0x80, 0x00, 0x01]), 0);
+        assert_eq!(metadata_len(&[0x00, 0x00, 0x02]), 0);
+        assert_eq!(metadata_len(&[0xA1, 0x00, 0x01]), 0);
+
+        // PUSH0 PUSH0 PUSH0 LOG1 STOP ADD
+        assert_eq!(metadata_len(&[0x5F, 0x5F, 0x5F, 0xA1, 0x00, 0x01]), 0);
+        // {"vyper": [0, 3, 4]} <length 0x000b>
+        assert_eq!(
+            metadata_len(&hex::decode("a165767970657283000304000b").unwrap()),
+            13
+        );
+        assert_eq!(
+            dasm.disassemble_hex("0x6080600052")
+                .unwrap()
+                .instructions()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn fork_marks_unsupported_opcodes_unknown() {
+        // PUSH0 TLOAD ADD
+        let bytecode = Bytecode::from(vec![0x5F, 0x5C, 0x01]);
+
+        let dasm = Disassembler::<Cancun>::builder()
+            .fork(HardFork::Berlin)
+            .build();
+        assert_eq!(
+            dasm.disassemble(&bytecode)
+                .unwrap()
+                .into_iter()
+                .map(|positioned| positioned.instruction)
+                .collect::<Vec<_>>(),
+            [
+                Instruction::Unknown(Unknown::new(0x5F)),
+                Instruction::Unknown(Unknown::new(0x5C)),
+                Instruction::Add(Add),
+            ]
+        );
+
+        let strict = Disassembler::<Cancun>::builder()
+            .stop_on_unknown(true)
+            .fork(HardFork::Shanghai)
+            .build();
+        assert!(matches!(
+            strict.disassemble(&bytecode),
+            Err(DasmError::UnknownOpCode {
+                position: 1,
+                opcode: 0x5C
+            })
+        ));
+    }
+
+    #[test]
+    fn annotated_disassembly_round_trips() {
+        // PUSH1 0x80 PUSH1 0x40 MSTORE <unknown 0x0C> <unknown 0xEF> PUSH32 <32 bytes> INVALID STOP
+        let mut bytes = vec![0x60, 0x80, 0x60, 0x40, 0x52, 0x0C, 0xEF, 0x7F];
+        bytes.extend(0..32);
+        bytes.extend([0xFE, 0x00]);
+        let bytecode = Bytecode::from(bytes);
+
+        let annotated = Disassembler::<Cancun>::default()
+            .disassemble_annotated(&bytecode)
+            .unwrap();
+
+        assert_eq!(annotated.len(), 8);
+        assert_eq!(
+            annotated.iter().map(|a| a.pc).collect::<Vec<_>>(),
+            [0, 2, 4, 5, 6, 7, 40, 41]
+        );
+        assert_eq!(
+            annotated[3].instruction,
+            Instruction::Unknown(Unknown::new(0x0C))
+        );
+        assert_eq!(
+            annotated
+                .iter()
+                .flat_map(|a| a.raw_bytes)
+                .copied()
+                .collect::<Vec<_>>(),
+            bytecode.as_bytes()
+        );
+
+        // PUSH1 0x80 STOP {"solc": 0x00081c} <length 0x000a>
+        let bytecode = Bytecode::from(hex::decode("608000a164736f6c634300081c000a").unwrap());
+        let annotated = Disassembler::<Cancun>::builder()
+            .skip_metadata(true)
+            .build()
+            .disassemble_annotated(&bytecode)
+            .unwrap();
+        assert_eq!(annotated.len(), 9);
+        assert_eq!(
+            annotated
+                .iter()
+                .flat_map(|a| a.raw_bytes)
+                .copied()
+                .collect::<Vec<_>>(),
+            bytecode.as_bytes()
+        );
+
+        let empty = Bytecode::default();
+        assert!(
+            Disassembler::<Cancun>::default()
+                .disassemble_annotated(&empty)
+                .unwrap()
+                .is_empty()
+        );
+
+        // Deployed bytecode commonly ends in data that decodes to a truncated `PUSHx`.
+        // STOP PUSH32 0x0102
+        let truncated = Bytecode::from(vec![0x00, 0x7F, 0x01, 0x02]);
+        let annotated = Disassembler::<Cancun>::default()
+            .disassemble_annotated(&truncated)
+            .unwrap();
+
+        assert_eq!(annotated.len(), 2);
+        assert_eq!(annotated[1].pc, 1);
+        assert_eq!(annotated[1].raw_bytes, &[0x7F, 0x01, 0x02]);
+        assert_eq!(
+            annotated
+                .iter()
+                .flat_map(|a| a.raw_bytes)
+                .copied()
+                .collect::<Vec<_>>(),
+            truncated.as_bytes()
+        );
+    }
+}
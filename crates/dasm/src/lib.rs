@@ -1,6 +1,12 @@
 //! EVM disassembler.
 
+pub mod analysis;
 pub mod assembly;
+pub mod builder;
 pub mod bytecode;
 pub mod disassembler;
 pub mod source;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use assembly::Annotated;
@@ -2,64 +2,225 @@
 
 use super::BytecodeSource;
 use crate::bytecode::Bytecode;
+use hex::FromHexError;
 use std::{
+    fmt::{self, Display},
     fs::File,
     io::{self, Read},
 };
+use thiserror::Error;
 
-impl BytecodeSource for File {
-    type Error = io::Error;
+/// The format of the contents of a bytecode file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FileFormat {
+    /// Raw bytecode bytes (e.g. a `.bin` file).
+    Raw,
+    /// Hex encoded bytecode with an optional `0x` prefix (e.g. a `.hex` file).
+    Hex,
+    /// Detect the format from the file contents, see [`FileFormat::detect`].
+    #[default]
+    Auto,
+}
+
+impl FileFormat {
+    /// Detects the format of file contents. Contents are treated as hex if they start with `0x`
+    /// or consist only of hex characters (ignoring surrounding whitespace), otherwise as raw
+    /// bytes.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_dasm::source::file::FileFormat;
+    /// assert_eq!(FileFormat::detect(b"0x6080"), FileFormat::Hex);
+    /// assert_eq!(FileFormat::detect(b"6080\n"), FileFormat::Hex);
+    /// assert_eq!(FileFormat::detect(&[0x60, 0x80]), FileFormat::Raw);
+    /// ```
+    #[must_use]
+    pub fn detect(contents: &[u8]) -> Self {
+        let trimmed = contents.trim_ascii();
+
+        if trimmed.starts_with(b"0x")
+            || (!trimmed.is_empty() && trimmed.iter().all(u8::is_ascii_hexdigit))
+        {
+            Self::Hex
+        } else {
+            Self::Raw
+        }
+    }
+}
+
+impl Display for FileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Raw => "raw",
+            Self::Hex => "hex",
+            Self::Auto => "auto",
+        };
+
+        f.pad(name)
+    }
+}
+
+/// A bytecode file with a known or detected format.
+#[derive(Debug)]
+pub struct FileSource {
+    /// The file to read.
+    file: File,
+    /// The format of the file contents.
+    format: FileFormat,
+}
+
+impl FileSource {
+    /// Creates a source that reads a file with the given format.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use oculars_dasm::source::{BytecodeSource, file::{FileFormat, FileSource}};
+    /// # use std::fs::File;
+    /// let source = FileSource::new(File::open("contract.hex").unwrap(), FileFormat::Hex);
+    /// let bytecode = source.extract().unwrap();
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(file: File, format: FileFormat) -> Self {
+        Self { file, format }
+    }
+}
+
+/// Errors that can happen when extracting bytecode from a file.
+#[derive(Debug, Error)]
+pub enum FileSourceError {
+    /// The file could not be read.
+    #[error("failed to read file: {0}")]
+    Io(#[from] io::Error),
+
+    /// The file contents could not be decoded.
+    #[error("failed to decode hex file ({format} format): {source}")]
+    Hex {
+        /// The requested format, [`FileFormat::Auto`] if the contents were detected as hex.
+        format: FileFormat,
+        /// The underlying hex decoding error.
+        source: FromHexError,
+    },
+}
+
+impl BytecodeSource for FileSource {
+    type Error = FileSourceError;
 
     fn extract(mut self) -> Result<Bytecode, Self::Error> {
         let mut bytes = vec![];
-        self.read_to_end(&mut bytes)?;
-
-        match String::try_from(bytes) {
-            Ok(hex_string) => {
-                if let Ok(bytecode) = BytecodeSource::extract(hex_string.as_str()) {
-                    Ok(bytecode)
-                } else {
-                    Ok(Bytecode::from(hex_string.into_bytes()))
-                }
+        self.file.read_to_end(&mut bytes)?;
+
+        let requested = self.format;
+        let format = match requested {
+            FileFormat::Auto => FileFormat::detect(&bytes),
+            format => format,
+        };
+
+        match format {
+            FileFormat::Hex => {
+                let trimmed = bytes.trim_ascii();
+                let digits = trimmed.strip_prefix(b"0x").unwrap_or(trimmed);
+
+                hex::decode(digits)
+                    .map(Bytecode::from)
+                    .map_err(|source| FileSourceError::Hex {
+                        format: requested,
+                        source,
+                    })
             }
-            Err(err) => Ok(Bytecode::from(err.into_bytes())),
+            FileFormat::Raw | FileFormat::Auto => Ok(Bytecode::from(bytes)),
         }
     }
 }
 
+impl BytecodeSource for File {
+    type Error = FileSourceError;
+
+    /// Extracts bytecode from a file, detecting its format (see [`FileFormat::Auto`]).
+    fn extract(self) -> Result<Bytecode, Self::Error> {
+        FileSource::new(self, FileFormat::Auto).extract()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::io::{Seek as _, SeekFrom, Write};
     use tempfile::tempfile;
 
-    #[test]
-    fn byte_file_extraction() {
+    /// Creates a temporary file with the given contents.
+    fn file_with(contents: &[u8]) -> File {
         let mut file = tempfile().unwrap();
-        file.write_all(&[10, 20, 30]).unwrap();
+        file.write_all(contents).unwrap();
         file.seek(SeekFrom::Start(0)).unwrap();
+        file
+    }
 
-        let bytecode = file.extract().unwrap();
+    #[test]
+    fn byte_file_extraction() {
+        let bytecode = file_with(&[10, 20, 30]).extract().unwrap();
         assert_eq!(bytecode.as_ref(), &[10, 20, 30]);
     }
 
     #[test]
     fn utf8_file_extraction() {
-        let mut file = tempfile().unwrap();
-        write!(file, "0x102030").unwrap();
-        file.seek(SeekFrom::Start(0)).unwrap();
-
-        let bytecode = file.extract().unwrap();
+        let bytecode = file_with(b"0x102030").extract().unwrap();
         assert_eq!(bytecode.as_ref(), &[0x10, 0x20, 0x30]);
     }
 
     #[test]
     fn invalid_utf8_file_extraction() {
-        let mut file = tempfile().unwrap();
-        file.write_all(&[0xC0]).unwrap();
-        file.seek(SeekFrom::Start(0)).unwrap();
-
-        let bytecode = file.extract().unwrap();
+        let bytecode = file_with(&[0xC0]).extract().unwrap();
         assert_eq!(bytecode.as_ref(), &[0xC0]);
     }
+
+    #[test]
+    fn raw_file_extraction() {
+        // raw bytes that happen to be valid hex characters.
+        let source = FileSource::new(file_with(b"6080"), FileFormat::Raw);
+        assert_eq!(source.extract().unwrap().as_ref(), b"6080");
+    }
+
+    #[test]
+    fn hex_file_extraction() {
+        let source = FileSource::new(file_with(b"0x6080604052\n"), FileFormat::Hex);
+        assert_eq!(
+            source.extract().unwrap().as_ref(),
+            &[0x60, 0x80, 0x60, 0x40, 0x52]
+        );
+
+        let source = FileSource::new(file_with(b"6080"), FileFormat::Auto);
+        assert_eq!(source.extract().unwrap().as_ref(), &[0x60, 0x80]);
+    }
+
+    #[test]
+    fn hex_file_errors_report_the_format() {
+        let error = FileSource::new(file_with(b"0x608"), FileFormat::Auto)
+            .extract()
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            FileSourceError::Hex {
+                format: FileFormat::Auto,
+                source: FromHexError::OddLength
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "failed to decode hex file (auto format): Odd number of digits"
+        );
+
+        let error = FileSource::new(file_with(&[0x60, 0x80]), FileFormat::Hex)
+            .extract()
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            FileSourceError::Hex {
+                format: FileFormat::Hex,
+                ..
+            }
+        ));
+        assert!(error.to_string().contains("(hex format)"));
+    }
 }
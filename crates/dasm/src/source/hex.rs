@@ -1,5 +1,7 @@
 //! Extraction of bytecode from a hex string.
 
+use std::io::{self, Read};
+
 use super::BytecodeSource;
 use crate::bytecode::Bytecode;
 use hex::FromHexError;
@@ -17,12 +19,178 @@ impl BytecodeSource for String {
 impl BytecodeSource for &str {
     type Error = FromHexError;
 
+    /// Decodes the hex string, reporting invalid characters at their index in `self`.
     fn extract(self) -> Result<Bytecode, Self::Error> {
-        let trimmed = self.trim().trim_start_matches("0x");
-        Ok(Bytecode::from(hex::decode(trimmed)?))
+        let trimmed = self.trim_end();
+        let digits = trimmed.trim_start().trim_start_matches("0x");
+        // `digits` is a suffix of `trimmed`, so this is the index of its first character in `self`.
+        let offset = trimmed.len() - digits.len();
+
+        hex::decode(digits)
+            .map(Bytecode::from)
+            .map_err(|error| match error {
+                FromHexError::InvalidHexCharacter { c, index } => {
+                    FromHexError::InvalidHexCharacter {
+                        c,
+                        index: index + offset,
+                    }
+                }
+                error => error,
+            })
+    }
+}
+
+/// Number of hex characters read from the underlying reader at once.
+const CHUNK_SIZE: usize = 8192;
+
+/// A reader that decodes a stream of hex characters into bytes without buffering the whole input.
+///
+/// An optional `0x` prefix (after leading whitespace) is skipped and ASCII whitespace is ignored,
+/// so line-wrapped hex dumps can be decoded. Invalid input results in an
+/// [`io::ErrorKind::InvalidData`] error that wraps a [`FromHexError`].
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::source::hex::HexReader;
+/// # use std::io::{Cursor, Read};
+/// let mut bytes = Vec::new();
+/// HexReader::new(Cursor::new("0x6001\n5a")).read_to_end(&mut bytes).unwrap();
+/// assert_eq!(bytes, [0x60, 0x01, 0x5A]);
+/// ```
+#[derive(Debug)]
+pub struct HexReader<R> {
+    /// The reader of hex characters.
+    inner: R,
+    /// Decoding state of the prefix.
+    prefix: Prefix,
+    /// High nibble that is waiting for its low nibble.
+    pending: Option<u8>,
+    /// Number of characters consumed so far, used for error reporting.
+    index: usize,
+}
+
+/// Decoding state of an optional `0x` prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Prefix {
+    /// No non-whitespace character has been read yet.
+    Start,
+    /// The first character was a `0` that may be followed by an `x`.
+    Zero,
+    /// The prefix has been skipped or there was none.
+    Done,
+}
+
+impl<R: Read> HexReader<R> {
+    /// Creates a hex decoding reader on top of a reader of hex characters.
+    #[must_use]
+    #[inline]
+    pub const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            prefix: Prefix::Start,
+            pending: None,
+            index: 0,
+        }
+    }
+
+    /// Returns the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Feeds a single character into the decoder and returns a byte once one is complete.
+    fn decode(&mut self, c: u8) -> io::Result<Option<u8>> {
+        let index = self.index;
+        self.index += 1;
+
+        if c.is_ascii_whitespace() {
+            return Ok(None);
+        }
+
+        match self.prefix {
+            Prefix::Start if c == b'0' => {
+                self.prefix = Prefix::Zero;
+                return Ok(None);
+            }
+            Prefix::Zero if c == b'x' => {
+                self.prefix = Prefix::Done;
+                return Ok(None);
+            }
+            Prefix::Zero => {
+                self.prefix = Prefix::Done;
+                self.pending = Some(0);
+            }
+            Prefix::Start => self.prefix = Prefix::Done,
+            Prefix::Done => {}
+        }
+
+        let nibble = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => {
+                return Err(invalid_data(FromHexError::InvalidHexCharacter {
+                    c: char::from(c),
+                    index,
+                }));
+            }
+        };
+
+        if let Some(high) = self.pending.take() {
+            Ok(Some((high << 4) | nibble))
+        } else {
+            self.pending = Some(nibble);
+            Ok(None)
+        }
     }
 }
 
+impl<R: Read> Read for HexReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut chunk = [0; CHUNK_SIZE];
+        let mut written = 0;
+
+        while written == 0 && !buf.is_empty() {
+            // Never read more characters than can be decoded into `buf`.
+            let wanted = buf.len().saturating_mul(2).min(CHUNK_SIZE);
+            let read = self.inner.read(&mut chunk[..wanted])?;
+
+            if read == 0 {
+                if self.prefix == Prefix::Zero || self.pending.is_some() {
+                    return Err(invalid_data(FromHexError::OddLength));
+                }
+                break;
+            }
+
+            for &c in &chunk[..read] {
+                if let Some(byte) = self.decode(c)? {
+                    buf[written] = byte;
+                    written += 1;
+                }
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+impl<R: Read> BytecodeSource for HexReader<R> {
+    type Error = io::Error;
+
+    fn extract(mut self) -> Result<Bytecode, Self::Error> {
+        let mut bytes = Vec::new();
+        self.read_to_end(&mut bytes)?;
+        Ok(Bytecode::from(bytes))
+    }
+}
+
+/// Wraps a hex decoding error into an [`io::Error`].
+fn invalid_data(error: FromHexError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +211,85 @@ mod tests {
             FromHexError::OddLength
         ));
     }
+
+    #[test]
+    fn hex_string_errors_index_the_original_string() {
+        assert_eq!(
+            "0x60zz".extract().unwrap_err(),
+            FromHexError::InvalidHexCharacter { c: 'z', index: 4 }
+        );
+        assert_eq!(
+            " \n0x60zz".extract().unwrap_err(),
+            FromHexError::InvalidHexCharacter { c: 'z', index: 6 }
+        );
+        assert_eq!(
+            "60zz".extract().unwrap_err(),
+            FromHexError::InvalidHexCharacter { c: 'z', index: 2 }
+        );
+    }
+
+    #[test]
+    fn hex_reader_decodes_large_input() {
+        use std::io::Cursor;
+
+        let hex = format!("0x{}\n", "60015a".repeat(100_000));
+        let bytecode = HexReader::new(Cursor::new(hex)).extract().unwrap();
+
+        assert_eq!(bytecode.len(), 300_000);
+        assert!(
+            bytecode
+                .as_bytes()
+                .chunks(3)
+                .all(|chunk| chunk == [0x60, 0x01, 0x5A])
+        );
+    }
+
+    #[test]
+    fn hex_reader_small_reads() {
+        use std::io::Cursor;
+
+        let mut reader = HexReader::new(Cursor::new(" 0x60\n01 5A\n"));
+        let mut byte = [0];
+        let mut bytes = Vec::new();
+
+        while reader.read(&mut byte).unwrap() == 1 {
+            bytes.push(byte[0]);
+        }
+
+        assert_eq!(bytes, [0x60, 0x01, 0x5A]);
+
+        let bytecode = HexReader::new(Cursor::new("00ab")).extract().unwrap();
+        assert_eq!(bytecode.as_bytes(), &[0x00, 0xAB]);
+        assert!(
+            HexReader::new(Cursor::new(""))
+                .extract()
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            HexReader::new(Cursor::new("0x"))
+                .extract()
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn hex_reader_errors() {
+        use std::io::Cursor;
+
+        /// Extracts the hex error of a failed extraction.
+        fn hex_error(hex: &'static str) -> FromHexError {
+            let error = HexReader::new(Cursor::new(hex)).extract().unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+            *error.into_inner().unwrap().downcast().unwrap()
+        }
+
+        assert_eq!(hex_error("0x10203"), FromHexError::OddLength);
+        assert_eq!(hex_error("0"), FromHexError::OddLength);
+        assert_eq!(
+            hex_error("0x60zz"),
+            FromHexError::InvalidHexCharacter { c: 'z', index: 4 }
+        );
+    }
 }
@@ -3,6 +3,8 @@
 pub mod bytes;
 pub mod file;
 pub mod hex;
+#[cfg(feature = "rpc")]
+pub mod rpc;
 
 use crate::bytecode::Bytecode;
 
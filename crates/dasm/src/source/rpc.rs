@@ -0,0 +1,181 @@
+//! Extraction of bytecode from a JSON-RPC node via `eth_getCode`.
+
+use super::BytecodeSource;
+use crate::bytecode::Bytecode;
+use hex::FromHexError;
+use serde_json::Value;
+use std::io;
+use thiserror::Error;
+
+/// A JSON-RPC node endpoint and a contract address whose code is fetched with `eth_getCode`.
+///
+/// # Example
+/// ```no_run
+/// # use oculars_dasm::{disassembler::Disassembler, source::rpc::RpcSource};
+/// # use upgrades::execution::Latest;
+/// let source = RpcSource::new(
+///     "https://ethereum-rpc.publicnode.com",
+///     "0xdAC17F958D2ee523a2206206994597C13D831ec7",
+/// );
+/// let assembly = Disassembler::<Latest>::default()
+///     .disassemble_from_source(source)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcSource {
+    /// URL of the JSON-RPC endpoint.
+    endpoint: String,
+    /// Address of the contract.
+    address: String,
+    /// Block number or tag at which the code is fetched.
+    block: String,
+}
+
+impl RpcSource {
+    /// Creates a source that fetches the code of `address` at the `latest` block.
+    #[must_use]
+    pub fn new<E: Into<String>, A: Into<String>>(endpoint: E, address: A) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            address: address.into(),
+            block: "latest".to_string(),
+        }
+    }
+
+    /// Sets the block number or tag (e.g. `latest`, `0x10d4f`) at which the code is fetched.
+    #[must_use]
+    pub fn at_block<B: Into<String>>(mut self, block: B) -> Self {
+        self.block = block.into();
+        self
+    }
+
+    /// Returns the JSON-RPC request body for `eth_getCode`.
+    fn request_body(&self) -> String {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_getCode",
+            "params": [self.address, self.block],
+        })
+        .to_string()
+    }
+}
+
+/// An error that can happen while fetching bytecode from a JSON-RPC node.
+#[derive(Debug, Error)]
+pub enum RpcSourceError {
+    /// The request could not be sent or the node responded with an HTTP error.
+    #[error("transport error: {0}")]
+    Transport(#[from] Box<ureq::Error>),
+
+    /// The response body could not be read.
+    #[error("failed to read response: {0}")]
+    Io(#[from] io::Error),
+
+    /// The response is not valid JSON.
+    #[error("invalid JSON response: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The node responded with a JSON-RPC error.
+    #[error("node returned error {code}: {message}")]
+    Rpc {
+        /// JSON-RPC error code.
+        code: i64,
+        /// JSON-RPC error message.
+        message: String,
+    },
+
+    /// The response contains neither a result nor an error.
+    #[error("response contains no result")]
+    MissingResult,
+
+    /// The result is not a valid hex string.
+    #[error("invalid hex in result: {0}")]
+    Hex(#[from] FromHexError),
+}
+
+impl BytecodeSource for RpcSource {
+    type Error = RpcSourceError;
+
+    fn extract(self) -> Result<Bytecode, Self::Error> {
+        let body = ureq::post(&self.endpoint)
+            .set("Content-Type", "application/json")
+            .send_string(&self.request_body())
+            .map_err(Box::new)?
+            .into_string()?;
+
+        parse_response(&body)
+    }
+}
+
+/// Parses the bytecode out of an `eth_getCode` JSON-RPC response.
+fn parse_response(body: &str) -> Result<Bytecode, RpcSourceError> {
+    let response: Value = serde_json::from_str(body)?;
+
+    if let Some(error) = response.get("error") {
+        return Err(RpcSourceError::Rpc {
+            code: error
+                .get("code")
+                .and_then(Value::as_i64)
+                .unwrap_or_default(),
+            message: error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+        });
+    }
+
+    let result = response
+        .get("result")
+        .and_then(Value::as_str)
+        .ok_or(RpcSourceError::MissingResult)?;
+
+    Ok(BytecodeSource::extract(result)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_body() {
+        let source = RpcSource::new("http://localhost:8545", "0x01").at_block("0x10");
+        let body: Value = serde_json::from_str(&source.request_body()).unwrap();
+
+        assert_eq!(body["method"], "eth_getCode");
+        assert_eq!(body["params"], serde_json::json!(["0x01", "0x10"]));
+    }
+
+    #[test]
+    fn response_parsing() {
+        let bytecode =
+            parse_response(r#"{"jsonrpc":"2.0","id":1,"result":"0x6080604052"}"#).unwrap();
+        assert_eq!(bytecode.as_ref(), &[0x60, 0x80, 0x60, 0x40, 0x52]);
+
+        let empty = parse_response(r#"{"jsonrpc":"2.0","id":1,"result":"0x"}"#).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn response_errors() {
+        assert!(matches!(
+            parse_response("not json"),
+            Err(RpcSourceError::Json(_))
+        ));
+        assert!(matches!(
+            parse_response(r#"{"jsonrpc":"2.0","id":1,"result":"0xzz"}"#),
+            Err(RpcSourceError::Hex(_))
+        ));
+        assert!(matches!(
+            parse_response(r#"{"jsonrpc":"2.0","id":1}"#),
+            Err(RpcSourceError::MissingResult)
+        ));
+        assert!(matches!(
+            parse_response(
+                r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"invalid address"}}"#
+            ),
+            Err(RpcSourceError::Rpc { code: -32602, message }) if message == "invalid address"
+        ));
+    }
+}
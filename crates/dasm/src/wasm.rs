@@ -0,0 +1,70 @@
+//! JavaScript bindings for running the disassembler in a browser.
+
+use serde_json::json;
+use upgrades::execution::Latest;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::disassembler::Disassembler;
+
+/// Disassembles hex encoded bytecode using the latest execution upgrade and returns the
+/// instructions as a JSON array of `{"position": .., "instruction": ..}` objects.
+///
+/// # Example
+/// ```
+/// # use oculars_dasm::wasm::disassemble_hex;
+/// let json = disassemble_hex("0x600100").unwrap();
+/// assert_eq!(
+///     json,
+///     r#"[{"instruction":{"immediate":"0x01","mnemonic":"PUSH1"},"position":0},{"instruction":{"mnemonic":"STOP"},"position":2}]"#
+/// );
+/// ```
+///
+/// # Errors
+/// Returns the error message if the bytecode cannot be disassembled.
+#[wasm_bindgen(js_name = disassembleHex)]
+pub fn disassemble_hex(hex: &str) -> Result<String, String> {
+    let assembly = Disassembler::<Latest>::default()
+        .disassemble_hex(hex)
+        .map_err(|error| error.to_string())?;
+
+    let instructions: Vec<_> = assembly
+        .instructions()
+        .iter()
+        .map(|positioned| {
+            json!({
+                "position": positioned.position,
+                "instruction": positioned.instruction,
+            })
+        })
+        .collect();
+
+    Ok(serde_json::Value::from(instructions).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassemble_hex_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&disassemble_hex("0x60806040520c").unwrap()).unwrap();
+
+        assert_eq!(
+            json,
+            json!([
+                {"position": 0, "instruction": {"mnemonic": "PUSH1", "immediate": "0x80"}},
+                {"position": 2, "instruction": {"mnemonic": "PUSH1", "immediate": "0x40"}},
+                {"position": 4, "instruction": {"mnemonic": "MSTORE"}},
+                {"position": 5, "instruction": {"opcode": 12}},
+            ])
+        );
+        assert_eq!(disassemble_hex("0x").unwrap(), "[]");
+    }
+
+    #[test]
+    fn disassemble_hex_errors() {
+        assert!(disassemble_hex("0x6").is_err());
+        assert!(disassemble_hex("0x61").is_err());
+    }
+}
@@ -0,0 +1,20 @@
+// Smoke test for the JavaScript bindings of the disassembler.
+//
+// Build the bindings and run the test with:
+//
+//     wasm-pack build crates/dasm --target nodejs --out-dir tests/wasm/pkg -- --features wasm
+//     node crates/dasm/tests/wasm/smoke.mjs
+
+import assert from "node:assert/strict";
+import { createRequire } from "node:module";
+
+const { disassembleHex } = createRequire(import.meta.url)("./pkg/oculars_dasm.js");
+
+assert.deepEqual(JSON.parse(disassembleHex("0x600100")), [
+  { position: 0, instruction: { mnemonic: "PUSH1", immediate: "0x01" } },
+  { position: 2, instruction: { mnemonic: "STOP" } },
+]);
+
+assert.throws(() => disassembleHex("0x61"));
+
+console.log("ok");
@@ -0,0 +1,254 @@
+//! Ethereum addresses.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use thiserror::Error;
+#[cfg(feature = "keccak")]
+use tiny_keccak::{Hasher, Keccak};
+
+/// A 20 byte Ethereum address.
+///
+/// Addresses are displayed with the mixed-case checksum of
+/// [EIP-55](https://eips.ethereum.org/EIPS/eip-55), or in lowercase without the `keccak` feature.
+///
+/// # Example
+/// ```
+/// # use oculars_primitives::Address;
+/// let address: Address = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse().unwrap();
+/// # #[cfg(feature = "keccak")]
+/// assert_eq!(address.to_string(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Address(pub [u8; 20]);
+
+impl Address {
+    /// The zero address.
+    pub const ZERO: Self = Self([0; 20]);
+
+    /// Returns the bytes of this address.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_primitives::Address;
+    /// assert_eq!(Address([0x11; 20]).into_bytes(), [0x11; 20]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn into_bytes(self) -> [u8; 20] {
+        self.0
+    }
+
+    /// Returns the address of the precompiled contract with the given index, which is the index
+    /// stored in the last byte of an otherwise zero address.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_primitives::Address;
+    /// let ecrecover = Address::precompile(0x01);
+    /// assert_eq!(ecrecover.to_string(), "0x0000000000000000000000000000000000000001");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn precompile(index: u8) -> Self {
+        let mut address = [0; 20];
+        address[19] = index;
+        Self(address)
+    }
+}
+
+/// Errors that can happen when parsing an [`Address`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AddressError {
+    /// The address is not valid hex.
+    #[error("invalid hex: {0}")]
+    Hex(#[from] hex::FromHexError),
+
+    /// The address does not consist of 40 hex characters.
+    #[error("expected 40 hex characters, got {0}")]
+    Length(usize),
+
+    /// The address is mixed-case but the checksum does not match.
+    #[error("invalid EIP-55 checksum")]
+    Checksum,
+}
+
+impl FromStr for Address {
+    type Err = AddressError;
+
+    /// Parses a hex encoded address with an optional `0x` prefix. Mixed-case addresses must have
+    /// a valid EIP-55 checksum, which is only verified with the `keccak` feature.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+
+        if digits.len() != 40 {
+            return Err(AddressError::Length(digits.len()));
+        }
+
+        let mut bytes = [0; 20];
+        hex::decode_to_slice(digits, &mut bytes)?;
+        let address = Self(bytes);
+
+        #[cfg(feature = "keccak")]
+        let is_mixed_case = digits.bytes().any(|c| c.is_ascii_lowercase())
+            && digits.bytes().any(|c| c.is_ascii_uppercase());
+
+        #[cfg(feature = "keccak")]
+        if is_mixed_case && checksummed_digits(address) != digits {
+            return Err(AddressError::Checksum);
+        }
+
+        Ok(address)
+    }
+}
+
+impl Display for Address {
+    /// Formats the address as `0x` prefixed hex with an EIP-55 checksum, or in lowercase without
+    /// the `keccak` feature.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "keccak")]
+        return write!(f, "0x{}", checksummed_digits(*self));
+
+        #[cfg(not(feature = "keccak"))]
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+impl From<[u8; 20]> for Address {
+    fn from(bytes: [u8; 20]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Address> for [u8; 20] {
+    fn from(address: Address) -> Self {
+        address.0
+    }
+}
+
+impl AsRef<[u8]> for Address {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Address {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Address {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Returns the 40 hex digits of an address with an EIP-55 checksum: a letter is uppercased if the
+/// corresponding nibble of the `keccak256` hash of the lowercase digits is at least 8.
+#[cfg(feature = "keccak")]
+pub(crate) fn checksummed_digits(address: Address) -> String {
+    let digits = hex::encode(address.0);
+
+    let mut hasher = Keccak::v256();
+    hasher.update(digits.as_bytes());
+    let mut hash = [0; 32];
+    hasher.finalize(&mut hash);
+
+    digits
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0F;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Checksummed addresses from EIP-55.
+    pub const EIP55_VECTORS: [&str; 8] = [
+        // All caps.
+        "0x52908400098527886E0F7030069857D2E4169EE7",
+        "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+        // All lower.
+        "0xde709f2102306220921060314715629080e2fb77",
+        "0x27b1fdb04752bbc536007a920d24acb045561c26",
+        // Normal.
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ];
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn eip55_checksums() {
+        for vector in EIP55_VECTORS {
+            let address: Address = vector.to_lowercase().parse().unwrap();
+            assert_eq!(address.to_string(), vector);
+            assert_eq!(vector.parse::<Address>(), Ok(address));
+        }
+    }
+
+    #[test]
+    fn address_parsing() {
+        let address = Address::from_str("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+        assert_eq!(
+            address,
+            "0X5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"
+                .strip_prefix("0X")
+                .unwrap()
+                .parse()
+                .unwrap()
+        );
+
+        #[cfg(feature = "keccak")]
+        assert_eq!(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".parse::<Address>(),
+            Err(AddressError::Checksum)
+        );
+        assert_eq!("0x1234".parse::<Address>(), Err(AddressError::Length(4)));
+        assert!(matches!(
+            "0xzzaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse::<Address>(),
+            Err(AddressError::Hex(_))
+        ));
+
+        assert_eq!(
+            Address::ZERO.to_string(),
+            "0x0000000000000000000000000000000000000000"
+        );
+        assert_eq!(<[u8; 20]>::from(Address([0x11; 20])), [0x11; 20]);
+    }
+
+    #[cfg(not(feature = "keccak"))]
+    #[test]
+    fn lowercase_without_checksums() {
+        for vector in EIP55_VECTORS {
+            let address: Address = vector.parse().unwrap();
+            assert_eq!(address.to_string(), vector.to_lowercase());
+        }
+    }
+
+    #[cfg(all(feature = "serde", feature = "keccak"))]
+    #[test]
+    fn address_serde() {
+        let address: Address = EIP55_VECTORS[4].parse().unwrap();
+        let json = serde_json::to_string(&address).unwrap();
+
+        assert_eq!(json, "\"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed\"");
+        assert_eq!(serde_json::from_str::<Address>(&json).unwrap(), address);
+    }
+}
@@ -0,0 +1,71 @@
+//! [EIP-55](https://eips.ethereum.org/EIPS/eip-55) mixed-case address checksums.
+
+use crate::{Address, address::checksummed_digits};
+
+/// Returns the `0x` prefixed hex encoding of an address with an EIP-55 checksum: a letter is
+/// uppercased if the corresponding nibble of the `keccak256` hash of the lowercase hex digits is
+/// at least 8.
+///
+/// # Example
+/// ```
+/// # use oculars_primitives::to_checksummed;
+/// let address = [
+///     0x5a, 0xae, 0xb6, 0x05, 0x3f, 0x3e, 0x94, 0xc9, 0xb9, 0xa0, 0x9f, 0x33, 0x66, 0x94, 0x35,
+///     0xe7, 0xef, 0x1b, 0xea, 0xed,
+/// ];
+/// assert_eq!(to_checksummed(address), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+/// ```
+#[must_use]
+pub fn to_checksummed(address: [u8; 20]) -> String {
+    format!("0x{}", checksummed_digits(Address(address)))
+}
+
+/// Returns [`true`] if a hex encoded address with an optional `0x` prefix has a valid EIP-55
+/// checksum.
+///
+/// # Example
+/// ```
+/// # use oculars_primitives::verify_checksum;
+/// assert!(verify_checksum("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+/// assert!(!verify_checksum("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+/// ```
+#[must_use]
+pub fn verify_checksum(address: &str) -> bool {
+    let digits = address.strip_prefix("0x").unwrap_or(address);
+    let mut bytes = [0; 20];
+
+    hex::decode_to_slice(digits, &mut bytes).is_ok() && to_checksummed(bytes)[2..] == *digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::tests::EIP55_VECTORS;
+
+    #[test]
+    fn eip55_test_vectors() {
+        for vector in EIP55_VECTORS {
+            let mut address = [0; 20];
+            hex::decode_to_slice(&vector[2..], &mut address).unwrap();
+
+            assert_eq!(to_checksummed(address), vector);
+            assert!(verify_checksum(vector));
+            assert!(verify_checksum(&vector[2..]));
+        }
+    }
+
+    #[test]
+    fn invalid_checksums() {
+        assert!(!verify_checksum(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"
+        ));
+        assert!(!verify_checksum(
+            "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"
+        ));
+        assert!(!verify_checksum("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"));
+        assert!(!verify_checksum(
+            "0xzaAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        ));
+        assert!(!verify_checksum(""));
+    }
+}
@@ -0,0 +1,9 @@
+//! Ethereum primitive types.
+
+pub mod address;
+#[cfg(feature = "keccak")]
+pub mod checksum;
+
+pub use address::Address;
+#[cfg(feature = "keccak")]
+pub use checksum::{to_checksummed, verify_checksum};
@@ -0,0 +1,162 @@
+//! Differences between hard forks.
+
+use std::fmt::{self, Display};
+
+use asm::Mnemonic;
+
+use crate::hardfork::HardFork;
+
+/// The EIPs and mnemonics that are added and removed when going from one hard fork to another.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ForkDiff {
+    /// EIPs that are included in the target hard fork but not in the source hard fork.
+    pub added_eips: Vec<u32>,
+    /// EIPs that are included in the source hard fork but not in the target hard fork.
+    pub removed_eips: Vec<u32>,
+    /// Mnemonics that are supported by the target hard fork but not by the source hard fork.
+    pub added_mnemonics: Vec<Mnemonic>,
+    /// Mnemonics that are supported by the source hard fork but not by the target hard fork.
+    pub removed_mnemonics: Vec<Mnemonic>,
+}
+
+impl ForkDiff {
+    /// Returns [`true`] if both hard forks include the same EIPs and support the same mnemonics.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{diff, hardfork::HardFork};
+    /// assert!(diff(HardFork::London, HardFork::London).is_empty());
+    /// assert!(!diff(HardFork::London, HardFork::Cancun).is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added_eips.is_empty()
+            && self.removed_eips.is_empty()
+            && self.added_mnemonics.is_empty()
+            && self.removed_mnemonics.is_empty()
+    }
+}
+
+/// Returns the EIPs and mnemonics that are added and removed when going from the hard fork `from`
+/// to the hard fork `to`.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::{diff, hardfork::HardFork};
+/// # use asm::Mnemonic;
+/// let diff = diff(HardFork::London, HardFork::Shanghai);
+/// assert_eq!(diff.added_mnemonics, vec![Mnemonic::PUSH0]);
+/// assert!(diff.added_eips.contains(&3855));
+/// assert!(diff.removed_mnemonics.is_empty());
+/// ```
+#[must_use]
+pub fn diff(from: HardFork, to: HardFork) -> ForkDiff {
+    let (from_eips, to_eips) = (from.eips(), to.eips());
+
+    let mnemonics = |a: HardFork, b: HardFork| {
+        Mnemonic::VARIANTS
+            .iter()
+            .copied()
+            .filter(|&mnemonic| b.supports_mnemonic(mnemonic) && !a.supports_mnemonic(mnemonic))
+            .collect()
+    };
+
+    ForkDiff {
+        added_eips: difference(&to_eips, &from_eips),
+        removed_eips: difference(&from_eips, &to_eips),
+        added_mnemonics: mnemonics(from, to),
+        removed_mnemonics: mnemonics(to, from),
+    }
+}
+
+/// Returns the EIPs of `a` that are not in `b`.
+fn difference(a: &[u32], b: &[u32]) -> Vec<u32> {
+    a.iter()
+        .copied()
+        .filter(|eip| b.binary_search(eip).is_err())
+        .collect()
+}
+
+impl Display for ForkDiff {
+    /// Formats the differences, one category per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Writes a comma separated list or `none` if the list is empty.
+        fn list<T: Display>(f: &mut fmt::Formatter<'_>, label: &str, items: &[T]) -> fmt::Result {
+            write!(f, "{label}: ")?;
+
+            if items.is_empty() {
+                write!(f, "none")?;
+            }
+
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{item}")?;
+            }
+
+            writeln!(f)
+        }
+
+        list(f, "added", &self.added_mnemonics)?;
+        list(f, "removed", &self.removed_mnemonics)?;
+        list(f, "added EIPs", &self.added_eips)?;
+        list(f, "removed EIPs", &self.removed_eips)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn london_to_cancun() {
+        let diff = diff(HardFork::London, HardFork::Cancun);
+
+        assert_eq!(
+            diff.added_mnemonics,
+            vec![
+                Mnemonic::BLOBHASH,
+                Mnemonic::BLOBBASEFEE,
+                Mnemonic::TLOAD,
+                Mnemonic::TSTORE,
+                Mnemonic::MCOPY,
+                Mnemonic::PUSH0,
+            ]
+        );
+        assert!(diff.removed_mnemonics.is_empty());
+        assert!(diff.removed_eips.is_empty());
+
+        for eip in [3855, 3860, 4844, 1153, 5656, 6780, 7516] {
+            assert!(diff.added_eips.contains(&eip), "missing EIP-{eip}");
+        }
+        assert!(!diff.added_eips.contains(&1559));
+    }
+
+    #[test]
+    fn reverse_diff() {
+        let forward = diff(HardFork::London, HardFork::Cancun);
+        let backward = diff(HardFork::Cancun, HardFork::London);
+
+        assert_eq!(backward.removed_mnemonics, forward.added_mnemonics);
+        assert_eq!(backward.removed_eips, forward.added_eips);
+        assert!(backward.added_mnemonics.is_empty());
+        assert!(backward.added_eips.is_empty());
+    }
+
+    #[test]
+    fn diff_fmt() {
+        assert_eq!(
+            diff(HardFork::London, HardFork::Shanghai)
+                .to_string()
+                .lines()
+                .take(2)
+                .collect::<Vec<_>>(),
+            ["added: PUSH0", "removed: none"]
+        );
+        assert_eq!(
+            diff(HardFork::Cancun, HardFork::Cancun).to_string(),
+            "added: none\nremoved: none\nadded EIPs: none\nremoved EIPs: none\n"
+        );
+    }
+}
@@ -13,6 +13,18 @@ pub trait Eip {
     /// ```
     const NUMBER: u32;
 
+    /// EIP title.
+    ///
+    /// EIPs implement [`Display`](std::fmt::Display) as `EIP-<NUMBER>: <TITLE>`.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{eips::eip1559::Eip1559, eip::{Eip}};
+    /// assert_eq!(Eip1559::TITLE, "Fee market change for ETH 1.0 chain");
+    /// assert_eq!(Eip1559.to_string(), "EIP-1559: Fee market change for ETH 1.0 chain");
+    /// ```
+    const TITLE: &'static str;
+
     /// Returns [`true`] if this EIP introduced a new [`Mnemonic`].
     /// ```
     /// # use oculars_upgrades::{eips::eip7::Eip7, eip::{Eip}};
@@ -54,6 +66,23 @@ pub mod macros {
         };
     }
 
+    /// Implements [`Display`](std::fmt::Display) for an EIP as `EIP-<NUMBER>: <TITLE>`.
+    macro_rules! display_eip {
+        ($eip: ident) => {
+            impl std::fmt::Display for $eip {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(
+                        f,
+                        "EIP-{}: {}",
+                        <Self as $crate::eip::Eip>::NUMBER,
+                        <Self as $crate::eip::Eip>::TITLE
+                    )
+                }
+            }
+        };
+    }
+
+    pub(crate) use display_eip;
     pub(crate) use introduced_mnemonics;
 }
 
@@ -69,6 +98,7 @@ mod tests {
 
         impl Eip for EipThatIntroducesStop {
             const NUMBER: u32 = 1;
+            const TITLE: &'static str = "Introduce STOP";
 
             fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
                 mnemonic == Mnemonic::STOP
@@ -88,6 +118,7 @@ mod tests {
 
         impl Eip for EipThatIntroducesStopAndAdd {
             const NUMBER: u32 = 1;
+            const TITLE: &'static str = "Introduce STOP and ADD";
 
             fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
                 mnemonic == Mnemonic::STOP || mnemonic == Mnemonic::ADD
@@ -105,6 +136,55 @@ mod tests {
         assert!(EipThatIntroducesStopAndAdd::introduced_instruction(&Add));
     }
 
+    #[test]
+    fn eip_display() {
+        use crate::eips::{eip7::Eip7, eip1153::Eip1153, eip2200::Eip2200};
+
+        assert_eq!(Eip7.to_string(), "EIP-7: DELEGATECALL");
+        assert_eq!(Eip1153.to_string(), "EIP-1153: Transient storage opcodes");
+        assert_eq!(
+            format!("{Eip2200}"),
+            "EIP-2200: Structured Definitions for Net Gas Metering"
+        );
+    }
+
+    #[test]
+    fn origin_eip_matches_introduced_mnemonics() {
+        use crate::eips::{
+            eip7::Eip7, eip140::Eip140, eip145::Eip145, eip211::Eip211, eip214::Eip214,
+            eip1014::Eip1014, eip1052::Eip1052, eip1153::Eip1153, eip1344::Eip1344,
+            eip1884::Eip1884, eip3198::Eip3198, eip3855::Eip3855, eip4844::Eip4844,
+            eip5656::Eip5656, eip7516::Eip7516,
+        };
+
+        fn check<E: Eip>() {
+            for &mnemonic in Mnemonic::VARIANTS {
+                assert_eq!(
+                    E::introduced_mnemonic(mnemonic),
+                    mnemonic.origin_eip() == Some(E::NUMBER),
+                    "EIP-{} {mnemonic}",
+                    E::NUMBER
+                );
+            }
+        }
+
+        check::<Eip7>();
+        check::<Eip140>();
+        check::<Eip145>();
+        check::<Eip211>();
+        check::<Eip214>();
+        check::<Eip1014>();
+        check::<Eip1052>();
+        check::<Eip1153>();
+        check::<Eip1344>();
+        check::<Eip1884>();
+        check::<Eip3198>();
+        check::<Eip3855>();
+        check::<Eip4844>();
+        check::<Eip5656>();
+        check::<Eip7516>();
+    }
+
     #[test]
     fn introduced_mnemonics_macro_works() {
         let m = Mnemonic::STOP;
@@ -40,6 +40,54 @@ pub trait EipSet {
             None => false,
         }
     }
+
+    /// Returns [`true`] if this set of EIPs contains an EIP with the given number.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{eip_set, eip_set::EipSet, eips::{eip1014::Eip1014, eip7::Eip7}};
+    /// type A = eip_set!(Eip7, Eip1014);
+    /// assert!(A::contains_eip(1014));
+    /// assert!(!A::contains_eip(1559));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn contains_eip(_number: u32) -> bool {
+        false
+    }
+
+    /// Returns [`true`] if this set of EIPs includes the EIP `E`.
+    ///
+    /// Since the EIP sets of execution upgrades are built on top of the previous upgrade's set,
+    /// an upgrade's [`ExecutionUpgrade::EipSet`](crate::execution::ExecutionUpgrade::EipSet)
+    /// includes every EIP activated by it or by any earlier upgrade.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{eip_set::EipSet, eips::{eip1559::Eip1559, eip4844::Eip4844}};
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, london::London};
+    /// type LondonEipSet = <London as ExecutionUpgrade>::EipSet;
+    /// assert!(LondonEipSet::includes::<Eip1559>());
+    /// assert!(!LondonEipSet::includes::<Eip4844>());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn includes<E: Eip>() -> bool {
+        Self::contains_eip(E::NUMBER)
+    }
+
+    /// Returns the numbers of all EIPs in this set, in ascending order.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::{eip_set, eip_set::EipSet, eips::{eip1014::Eip1014, eip7::Eip7}};
+    /// type A = eip_set!(Eip1014, Eip7);
+    /// assert_eq!(A::eips(), vec![7, 1014]);
+    /// ```
+    #[must_use]
+    fn eips() -> Vec<u32> {
+        Vec::new()
+    }
 }
 
 impl EipSet for () {}
@@ -49,6 +97,21 @@ impl<A: Eip, B: EipSet> EipSet for (A, B) {
     fn supports_mnemonic(mnemonic: Mnemonic) -> bool {
         A::introduced_mnemonic(mnemonic) || B::supports_mnemonic(mnemonic)
     }
+
+    #[inline]
+    fn contains_eip(number: u32) -> bool {
+        A::NUMBER == number || B::contains_eip(number)
+    }
+
+    fn eips() -> Vec<u32> {
+        let mut eips = B::eips();
+
+        if let Err(index) = eips.binary_search(&A::NUMBER) {
+            eips.insert(index, A::NUMBER);
+        }
+
+        eips
+    }
 }
 
 /// EIP set macros.
@@ -76,7 +139,8 @@ mod tests {
     use super::*;
     use crate::{
         eip_set,
-        eips::{eip2::Eip2, eip7::Eip7},
+        eips::{eip2::Eip2, eip7::Eip7, eip1559::Eip1559, eip2929::Eip2929, eip3855::Eip3855},
+        execution::{ExecutionUpgrade, berlin, london},
     };
 
     #[test]
@@ -85,4 +149,43 @@ mod tests {
         assert!(A::supports_mnemonic(Mnemonic::DELEGATECALL));
         assert!(!A::supports_mnemonic(Mnemonic::STOP));
     }
+
+    #[test]
+    fn eip_set_includes() {
+        type A = eip_set!(Eip2, Eip7);
+        assert!(A::includes::<Eip2>());
+        assert!(A::includes::<Eip7>());
+        assert!(!A::includes::<Eip1559>());
+        assert!(!<()>::includes::<Eip2>());
+    }
+
+    #[test]
+    fn eip_set_eips() {
+        type A = eip_set!(Eip7, Eip2, Eip7);
+        assert_eq!(A::eips(), vec![2, 7]);
+        assert!(<()>::eips().is_empty());
+
+        let london = <london::London as ExecutionUpgrade>::EipSet::eips();
+        assert!(london.contains(&1559));
+        assert!(london.is_sorted());
+        assert!(
+            london
+                .iter()
+                .all(|&eip| <london::London as ExecutionUpgrade>::EipSet::contains_eip(eip))
+        );
+    }
+
+    #[test]
+    fn upgrade_eip_set_includes() {
+        type Berlin = <berlin::Berlin as ExecutionUpgrade>::EipSet;
+        type London = <london::London as ExecutionUpgrade>::EipSet;
+
+        assert!(Berlin::includes::<Eip2929>());
+        assert!(!Berlin::includes::<Eip1559>());
+
+        assert!(London::includes::<Eip1559>());
+        assert!(London::includes::<Eip2929>());
+        assert!(London::includes::<Eip7>());
+        assert!(!London::includes::<Eip3855>());
+    }
 }
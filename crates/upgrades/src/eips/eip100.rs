@@ -27,11 +27,15 @@
 //!
 //! Vitalik Buterin (@vbuterin), "EIP-100: Change difficulty adjustment to target mean block time including uncles," Ethereum Improvement Proposals, no. 100, April 2016. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-100>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-100: Change difficulty adjustment to target mean block time including uncles.
 pub struct Eip100;
 
 impl Eip for Eip100 {
     const NUMBER: u32 = 100;
+    const TITLE: &'static str =
+        "Change difficulty adjustment to target mean block time including uncles";
 }
+
+display_eip!(Eip100);
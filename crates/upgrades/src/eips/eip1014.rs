@@ -100,16 +100,119 @@
 //!
 //! Vitalik Buterin (@vbuterin), "EIP-1014: Skinny CREATE2," Ethereum Improvement Proposals, no. 1014, April 2018. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1014>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
+#[cfg(feature = "keccak")]
+use primitives::Address;
 
 /// EIP-1014: Skinny CREATE2.
 pub struct Eip1014;
 
 impl Eip for Eip1014 {
     const NUMBER: u32 = 1014;
+    const TITLE: &'static str = "Skinny CREATE2";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, CREATE2)
     }
 }
+
+display_eip!(Eip1014);
+
+/// Computes the address of a contract deployed with `CREATE2`, which is the last 20 bytes of
+/// `keccak256(0xff ++ deployer ++ salt ++ init_code_hash)`.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip1014::create2_address;
+/// # use primitives::Address;
+/// // `init_code_hash` is `keccak256(0x00)`.
+/// let init_code_hash = [
+///     0xbc, 0x36, 0x78, 0x9e, 0x7a, 0x1e, 0x28, 0x14, 0x36, 0x46, 0x42, 0x29, 0x82, 0x8f, 0x81,
+///     0x7d, 0x66, 0x12, 0xf7, 0xb4, 0x77, 0xd6, 0x65, 0x91, 0xff, 0x96, 0xa9, 0xe0, 0x64, 0xbc,
+///     0xc9, 0x8a,
+/// ];
+/// let address = create2_address(Address::ZERO, [0; 32], init_code_hash);
+///
+/// assert_eq!(address.to_string(), "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38");
+/// ```
+#[cfg(feature = "keccak")]
+#[must_use]
+pub fn create2_address(deployer: Address, salt: [u8; 32], init_code_hash: [u8; 32]) -> Address {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let mut hasher = Keccak::v256();
+    hasher.update(&[0xFF]);
+    hasher.update(&deployer.0);
+    hasher.update(&salt);
+    hasher.update(&init_code_hash);
+
+    let mut hash = [0; 32];
+    hasher.finalize(&mut hash);
+
+    let mut address = Address::ZERO;
+    address.0.copy_from_slice(&hash[12..]);
+    address
+}
+
+#[cfg(all(test, feature = "keccak"))]
+mod tests {
+    use super::*;
+    use tiny_keccak::{Hasher, Keccak};
+
+    /// Computes the `CREATE2` address from hex encoded inputs.
+    fn address(deployer: &str, salt: &str, init_code: &str) -> String {
+        let mut hasher = Keccak::v256();
+        hasher.update(&hex::decode(init_code).unwrap());
+        let mut init_code_hash = [0; 32];
+        hasher.finalize(&mut init_code_hash);
+
+        hex::encode(create2_address(
+            deployer.parse().unwrap(),
+            hex::decode(salt).unwrap().try_into().unwrap(),
+            init_code_hash,
+        ))
+    }
+
+    #[test]
+    fn eip_test_vectors() {
+        let zero_address = "0".repeat(40);
+        let zero_salt = "0".repeat(64);
+        let deadbeef = format!("deadbeef{}", "0".repeat(32));
+        let feed_salt = format!("{}feed{}", "0".repeat(24), "0".repeat(36));
+        let low_deadbeef = format!("{}deadbeef", "0".repeat(32));
+        let cafebabe_salt = format!("{}cafebabe", "0".repeat(56));
+
+        assert_eq!(
+            address(&zero_address, &zero_salt, "00"),
+            "4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38"
+        );
+        assert_eq!(
+            address(&deadbeef, &zero_salt, "00"),
+            "b928f69bb1d91cd65274e3c79d8986362984fda3"
+        );
+        assert_eq!(
+            address(&deadbeef, &feed_salt, "00"),
+            "d04116cdd17bebe565eb2422f2497e06cc1c9833"
+        );
+        assert_eq!(
+            address(&zero_address, &zero_salt, "deadbeef"),
+            "70f2b2914a2a4b783faefb75f459a580616fcb5e"
+        );
+        assert_eq!(
+            address(&low_deadbeef, &cafebabe_salt, "deadbeef"),
+            "60f3f640a8508fc6a86d45df051962668e1e8ac7"
+        );
+        assert_eq!(
+            address(&low_deadbeef, &cafebabe_salt, &"deadbeef".repeat(11)),
+            "1d8bfdc5d46dc4f61d6b6115972536ebe6a8854c"
+        );
+        assert_eq!(
+            address(&zero_address, &zero_salt, ""),
+            "e33c0c7f7df4809055c3eba6c09cfe4baf1bd9e0"
+        );
+    }
+}
@@ -64,15 +64,21 @@
 
 use asm::Mnemonic;
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 
 /// EIP-1052: EXTCODEHASH opcode.
 pub struct Eip1052;
 
 impl Eip for Eip1052 {
     const NUMBER: u32 = 1052;
+    const TITLE: &'static str = "EXTCODEHASH opcode";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, EXTCODEHASH)
     }
 }
+
+display_eip!(Eip1052);
@@ -114,11 +114,14 @@
 //!
 //! Antonio Salazar Cardozo (@shadowfiend), Zachary Williamson (@zac-williamson), "EIP-1108: Reduce `alt_bn128` precompile gas costs," Ethereum Improvement Proposals, no. 1108, May 2018. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1108>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-1108: Reduce `alt_bn128` precompile gas costs.
 pub struct Eip1108;
 
 impl Eip for Eip1108 {
     const NUMBER: u32 = 1108;
+    const TITLE: &'static str = "Reduce `alt_bn128` precompile gas costs";
 }
+
+display_eip!(Eip1108);
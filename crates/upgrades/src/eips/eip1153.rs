@@ -244,15 +244,21 @@
 //!
 //! Alexey Akhunov (@`AlexeyAkhunov`), Moody Salem (@moodysalem), "EIP-1153: Transient storage opcodes," Ethereum Improvement Proposals, no. 1153, June 2018. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1153>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 
-/// Transient storage opcodes.
+/// EIP-1153: Transient storage opcodes.
 pub struct Eip1153;
 
 impl Eip for Eip1153 {
     const NUMBER: u32 = 1153;
+    const TITLE: &'static str = "Transient storage opcodes";
 
     fn introduced_mnemonic(mnemonic: asm::Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, TLOAD, TSTORE)
     }
 }
+
+display_eip!(Eip1153);
@@ -44,11 +44,14 @@
 //!
 //! Afri Schoedon (@5chdn), "EIP-1234: Constantinople Difficulty Bomb Delay and Block Reward Adjustment," Ethereum Improvement Proposals, no. 1234, July 2018. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1234>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-1234: Constantinople Difficulty Bomb Delay and Block Reward Adjustment.
 pub struct Eip1234;
 
 impl Eip for Eip1234 {
     const NUMBER: u32 = 1234;
+    const TITLE: &'static str = "Constantinople Difficulty Bomb Delay and Block Reward Adjustment";
 }
+
+display_eip!(Eip1234);
@@ -322,11 +322,14 @@
 //!
 //! Wei Tang (@sorpaas), "EIP-1283: Net gas metering for SSTORE without dirty maps," Ethereum Improvement Proposals, no. 1283, August 2018. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1283>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-1283: Net gas metering for SSTORE without dirty maps.
 pub struct Eip1283;
 
 impl Eip for Eip1283 {
     const NUMBER: u32 = 1283;
+    const TITLE: &'static str = "Net gas metering for SSTORE without dirty maps";
 }
+
+display_eip!(Eip1283);
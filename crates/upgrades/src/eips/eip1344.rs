@@ -33,7 +33,10 @@
 //!
 //! Richard Meissner (@rmeissner), Bryant Eisenbach (@fubuloubu), "EIP-1344: `ChainID` opcode," Ethereum Improvement Proposals, no. 1344, August 2018. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1344>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
 /// EIP-1344: `ChainID` opcode.
@@ -41,8 +44,11 @@ pub struct Eip1344;
 
 impl Eip for Eip1344 {
     const NUMBER: u32 = 1344;
+    const TITLE: &'static str = "`ChainID` opcode";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, CHAINID)
     }
 }
+
+display_eip!(Eip1344);
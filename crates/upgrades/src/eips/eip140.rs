@@ -47,7 +47,10 @@
 //!
 //! Alex Beregszaszi (@axic), Nikolai Mushegian <nikolai@nexusdev.us>, "EIP-140: REVERT instruction," Ethereum Improvement Proposals, no. 140, February 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-140>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
 /// EIP-140: REVERT instruction.
@@ -55,8 +58,11 @@ pub struct Eip140;
 
 impl Eip for Eip140 {
     const NUMBER: u32 = 140;
+    const TITLE: &'static str = "REVERT instruction";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, REVERT)
     }
 }
+
+display_eip!(Eip140);
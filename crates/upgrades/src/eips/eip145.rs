@@ -381,7 +381,10 @@
 //!
 //! Alex Beregszaszi (@axic), Paweł Bylica (@chfast), "EIP-145: Bitwise shifting instructions in EVM," Ethereum Improvement Proposals, no. 145, February 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-145>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
 /// EIP-145: Bitwise shifting instructions in EVM.
@@ -389,8 +392,11 @@ pub struct Eip145;
 
 impl Eip for Eip145 {
     const NUMBER: u32 = 145;
+    const TITLE: &'static str = "Bitwise shifting instructions in EVM";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, SHL, SHR, SAR)
     }
 }
+
+display_eip!(Eip145);
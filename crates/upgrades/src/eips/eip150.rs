@@ -61,11 +61,44 @@
 //!
 //! Vitalik Buterin (@vbuterin), "EIP-150: Gas cost changes for IO-heavy operations," Ethereum Improvement Proposals, no. 150, September 2016. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-150>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+
+/// Gas cost of `EXTCODESIZE` and base gas cost of `EXTCODECOPY` before EIP-150.
+pub const LEGACY_EXTCODE_GAS: u64 = 20;
+
+/// Gas cost of `EXTCODESIZE` and base gas cost of `EXTCODECOPY` as defined in [EIP-150](`Eip150`).
+pub const EXTCODE_GAS: u64 = 700;
+
+/// Gas cost of `BALANCE` before EIP-150.
+pub const LEGACY_BALANCE_GAS: u64 = 20;
+
+/// Gas cost of `BALANCE` as defined in [EIP-150](`Eip150`).
+pub const BALANCE_GAS: u64 = 400;
+
+/// Gas cost of `SLOAD` before EIP-150.
+pub const LEGACY_SLOAD_GAS: u64 = 50;
+
+/// Gas cost of `SLOAD` as defined in [EIP-150](`Eip150`).
+pub const SLOAD_GAS: u64 = 200;
+
+/// Base gas cost of `CALL`, `CALLCODE` and `DELEGATECALL` before EIP-150.
+pub const LEGACY_CALL_GAS: u64 = 40;
+
+/// Base gas cost of `CALL`, `CALLCODE` and `DELEGATECALL` as defined in [EIP-150](`Eip150`).
+pub const CALL_GAS: u64 = 700;
+
+/// Base gas cost of `SELFDESTRUCT` before EIP-150.
+pub const LEGACY_SELFDESTRUCT_GAS: u64 = 0;
+
+/// Base gas cost of `SELFDESTRUCT` as defined in [EIP-150](`Eip150`).
+pub const SELFDESTRUCT_GAS: u64 = 5000;
 
 /// EIP-150: Gas cost changes for IO-heavy operations.
 pub struct Eip150;
 
 impl Eip for Eip150 {
     const NUMBER: u32 = 150;
+    const TITLE: &'static str = "Gas cost changes for IO-heavy operations";
 }
+
+display_eip!(Eip150);
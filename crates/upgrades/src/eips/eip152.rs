@@ -253,11 +253,19 @@
 //!
 //! Tjaden Hess <tah83@cornell.edu>, Matt Luongo (@mhluongo), Piotr Dyraga (@pdyraga), James Hancock (@`MadeOfTin`), "EIP-152: Add BLAKE2 compression function `F` precompile," Ethereum Improvement Proposals, no. 152, October 2016. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-152>.
 
-use crate::eip::Eip;
+use primitives::Address;
+
+use crate::eip::{Eip, macros::display_eip};
+
+/// Address of the `BLAKE2F` precompile.
+pub const BLAKE2F_ADDRESS: Address = Address::precompile(0x09);
 
 /// EIP-152: Add BLAKE2 compression function `F` precompile.
 pub struct Eip152;
 
 impl Eip for Eip152 {
     const NUMBER: u32 = 152;
+    const TITLE: &'static str = "Add BLAKE2 compression function `F` precompile";
 }
+
+display_eip!(Eip152);
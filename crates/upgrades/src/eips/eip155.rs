@@ -60,11 +60,115 @@
 //!
 //! Vitalik Buterin (@vbuterin), "EIP-155: Simple replay attack protection," Ethereum Improvement Proposals, no. 155, October 2016. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-155>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+use thiserror::Error;
 
 /// EIP-155: Simple replay attack protection.
 pub struct Eip155;
 
 impl Eip for Eip155 {
     const NUMBER: u32 = 155;
+    const TITLE: &'static str = "Simple replay attack protection";
+}
+
+display_eip!(Eip155);
+
+/// The chain id is too large for its `v` value to fit in a [`u64`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the v value of chain id {chain_id} overflows a u64")]
+pub struct ChainIdOverflowError {
+    /// The chain id that was too large.
+    pub chain_id: u64,
+}
+
+/// Returns the `v` value of a replay protected legacy transaction signature, which is
+/// `chain_id * 2 + 35 + y_parity`.
+///
+/// # Errors
+/// Returns an error if the `v` value does not fit in a [`u64`].
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip155::v_for_chain;
+/// assert_eq!(v_for_chain(1, false), Ok(37));
+/// assert_eq!(v_for_chain(1, true), Ok(38));
+/// assert!(v_for_chain(u64::MAX, false).is_err());
+/// ```
+#[inline]
+pub const fn v_for_chain(chain_id: u64, y_parity: bool) -> Result<u64, ChainIdOverflowError> {
+    let v = match chain_id.checked_mul(2) {
+        Some(doubled) => doubled.checked_add(35 + y_parity as u64),
+        None => None,
+    };
+
+    match v {
+        Some(v) => Ok(v),
+        None => Err(ChainIdOverflowError { chain_id }),
+    }
+}
+
+/// Returns the chain id encoded in the `v` value of a legacy transaction signature or [`None`]
+/// if the signature is not replay protected (`v` is `27` or `28`).
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip155::chain_id_from_v;
+/// assert_eq!(chain_id_from_v(37), Some(1));
+/// assert_eq!(chain_id_from_v(38), Some(1));
+/// assert_eq!(chain_id_from_v(27), None);
+/// ```
+#[must_use]
+#[inline]
+pub const fn chain_id_from_v(v: u64) -> Option<u64> {
+    match v.checked_sub(35) {
+        Some(v) => Some(v / 2),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mainnet_v() {
+        assert_eq!(v_for_chain(1, false), Ok(37));
+        assert_eq!(v_for_chain(1, true), Ok(38));
+        assert_eq!(chain_id_from_v(37), Some(1));
+        assert_eq!(chain_id_from_v(38), Some(1));
+    }
+
+    #[test]
+    fn pre_eip155_v() {
+        assert_eq!(chain_id_from_v(27), None);
+        assert_eq!(chain_id_from_v(28), None);
+        assert_eq!(chain_id_from_v(0), None);
+    }
+
+    #[test]
+    fn v_overflow() {
+        let max_chain_id = (u64::MAX - 36) / 2;
+        assert_eq!(v_for_chain(max_chain_id, true), Ok(u64::MAX - 1));
+        assert_eq!(chain_id_from_v(u64::MAX - 1), Some(max_chain_id));
+
+        assert_eq!(v_for_chain(max_chain_id + 1, false), Ok(u64::MAX));
+
+        for chain_id in [max_chain_id + 1, u64::MAX / 2 + 1, u64::MAX] {
+            assert_eq!(
+                v_for_chain(chain_id, true),
+                Err(ChainIdOverflowError { chain_id })
+            );
+        }
+    }
+
+    #[test]
+    fn v_round_trip() {
+        for chain_id in [1, 5, 10, 137, 8453, 42161, 11_155_111] {
+            for y_parity in [false, true] {
+                let v = v_for_chain(chain_id, y_parity).unwrap();
+                assert_eq!(chain_id_from_v(v), Some(chain_id));
+                assert_eq!((v - 35) % 2 == 1, y_parity);
+            }
+        }
+    }
 }
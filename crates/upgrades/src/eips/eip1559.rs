@@ -317,11 +317,14 @@
 //!
 //! Vitalik Buterin (@vbuterin), Eric Conner (@econoar), Rick Dudley (@`AFDudley`), Matthew Slipper (@mslipper), Ian Norden (@i-norden), Abdelhamid Bakhta (@abdelhamidbakhta), "EIP-1559: Fee market change for ETH 1.0 chain," Ethereum Improvement Proposals, no. 1559, April 2019. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1559>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-1559: Fee market change for ETH 1.0 chain.
 pub struct Eip1559;
 
 impl Eip for Eip1559 {
     const NUMBER: u32 = 1559;
+    const TITLE: &'static str = "Fee market change for ETH 1.0 chain";
 }
+
+display_eip!(Eip1559);
@@ -17,11 +17,20 @@
 //!
 //! Vitalik Buterin (@vbuterin), "EIP-160: EXP cost increase," Ethereum Improvement Proposals, no. 160, October 2016. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-160>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+
+/// Gas charged for every byte of the `EXP` exponent before EIP-160.
+pub const LEGACY_EXP_BYTE_GAS: u64 = 10;
+
+/// Gas charged for every byte of the `EXP` exponent as defined in [EIP-160](`Eip160`).
+pub const EXP_BYTE_GAS: u64 = 50;
 
 /// EIP-160: EXP cost increase.
 pub struct Eip160;
 
 impl Eip for Eip160 {
     const NUMBER: u32 = 160;
+    const TITLE: &'static str = "EXP cost increase";
 }
+
+display_eip!(Eip160);
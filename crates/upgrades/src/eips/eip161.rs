@@ -56,11 +56,14 @@
 //!
 //! Gavin Wood (@gavofyork), "EIP-161: State trie clearing (invariant-preserving alternative)," Ethereum Improvement Proposals, no. 161, October 2016. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-161>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-161: State trie cleaning.
 pub struct Eip161;
 
 impl Eip for Eip161 {
     const NUMBER: u32 = 161;
+    const TITLE: &'static str = "State trie cleaning";
 }
+
+display_eip!(Eip161);
@@ -17,14 +17,44 @@
 //!
 //! Currently, there remains one slight quadratic vulnerability in Ethereum: when a contract is called, even though the call takes a constant amount of gas, the call can trigger O(n) cost in terms of reading the code from disk, preprocessing the code for VM execution, and also adding O(n) data to the Merkle proof for the block's proof-of-validity. At current gas levels, this is acceptable even if suboptimal. At the higher gas levels that could be triggered in the future, possibly very soon due to dynamic gas limit rules, this would become a greater concern—not nearly as serious as recent denial of service attacks, but still inconvenient especially for future light clients verifying proofs of validity or invalidity. The solution is to put a hard cap on the size of an object that can be saved to the blockchain, and do so non-disruptively by setting the cap at a value slightly higher than what is feasible with current gas limits.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// Maximum smart contract bytecode size as defined in [EIP-170](`Eip170`).
 pub const MAX_CODE_SIZE: usize = 0x6000;
 
+/// Returns [`true`] if the code is larger than [`MAX_CODE_SIZE`].
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip170::{exceeds_limit, MAX_CODE_SIZE};
+/// assert!(!exceeds_limit(&[0; MAX_CODE_SIZE]));
+/// assert!(exceeds_limit(&[0; MAX_CODE_SIZE + 1]));
+/// ```
+#[must_use]
+#[inline]
+pub const fn exceeds_limit(code: &[u8]) -> bool {
+    code.len() > MAX_CODE_SIZE
+}
+
 /// EIP-170: Contract code size limit.
 pub struct Eip170;
 
 impl Eip for Eip170 {
     const NUMBER: u32 = 170;
+    const TITLE: &'static str = "Contract code size limit";
+}
+
+display_eip!(Eip170);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_size_limit() {
+        assert_eq!(MAX_CODE_SIZE, 24576);
+        assert!(!exceeds_limit(&[]));
+        assert!(!exceeds_limit(&vec![0; 24576]));
+        assert!(exceeds_limit(&vec![0; 24577]));
+    }
 }
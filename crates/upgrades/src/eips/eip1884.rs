@@ -99,16 +99,28 @@
 //!
 //! Martin Holst Swende (@holiman), "EIP-1884: Repricing for trie-size-dependent opcodes," Ethereum Improvement Proposals, no. 1884, March 2019. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-1884>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
+/// Gas cost of `SLOAD` as defined in [EIP-1884](`Eip1884`).
+pub const SLOAD_GAS: u64 = 800;
+
+/// Gas cost of `BALANCE` and `EXTCODEHASH` as defined in [EIP-1884](`Eip1884`).
+pub const BALANCE_GAS: u64 = 700;
+
 /// EIP-1884: Repricing for trie-size-dependent opcodes.
 pub struct Eip1884;
 
 impl Eip for Eip1884 {
-    const NUMBER: u32 = 1844;
+    const NUMBER: u32 = 1884;
+    const TITLE: &'static str = "Repricing for trie-size-dependent opcodes";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, SELFBALANCE)
     }
 }
+
+display_eip!(Eip1884);
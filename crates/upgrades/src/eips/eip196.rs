@@ -83,11 +83,28 @@
 //!
 //! Christian Reitwiessner <chris@ethereum.org>, "EIP-196: Precompiled contracts for addition and scalar multiplication on the elliptic curve `alt_bn128`," Ethereum Improvement Proposals, no. 196, February 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-196>.
 
-use crate::eip::Eip;
+use primitives::Address;
+
+use crate::eip::{Eip, macros::display_eip};
+
+/// Address of the `ECADD` precompile.
+pub const EC_ADD_ADDRESS: Address = Address::precompile(0x06);
+
+/// Address of the `ECMUL` precompile.
+pub const EC_MUL_ADDRESS: Address = Address::precompile(0x07);
+
+/// Gas cost of the `ECADD` precompile, later reduced by [EIP-1108](super::eip1108).
+pub const EC_ADD_GAS: u64 = 500;
+
+/// Gas cost of the `ECMUL` precompile, later reduced by [EIP-1108](super::eip1108).
+pub const EC_MUL_GAS: u64 = 40_000;
 
 /// EIP-196: Precompiled contracts for addition and scalar multiplication on the elliptic curve `alt_bn128`.
 pub struct Eip196;
 
 impl Eip for Eip196 {
     const NUMBER: u32 = 196;
+    const TITLE: &'static str = "Precompiled contracts for addition and scalar multiplication on the elliptic curve `alt_bn128`";
 }
+
+display_eip!(Eip196);
@@ -123,11 +123,47 @@
 //!
 //! Vitalik Buterin <vitalik@ethereum.org>, Christian Reitwiessner <chris@ethereum.org>, "EIP-197: Precompiled contracts for optimal ate pairing check on the elliptic curve `alt_bn128`," Ethereum Improvement Proposals, no. 197, February 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-197>.
 
-use crate::eip::Eip;
+use primitives::Address;
+
+use crate::eip::{Eip, macros::display_eip};
+
+/// Address of the `ECPAIRING` precompile.
+pub const EC_PAIRING_ADDRESS: Address = Address::precompile(0x08);
+
+/// Base gas cost of the `ECPAIRING` precompile, later reduced by [EIP-1108](super::eip1108).
+pub const EC_PAIRING_BASE_GAS: u64 = 100_000;
+
+/// Gas cost of every point pair checked by the `ECPAIRING` precompile, later reduced by
+/// [EIP-1108](super::eip1108).
+pub const EC_PAIRING_PER_POINT_GAS: u64 = 80_000;
+
+/// Size of a `(G_1, G_2)` point pair in the `ECPAIRING` input.
+pub const EC_PAIRING_POINT_SIZE: usize = 192;
+
+/// Returns the gas cost of the `ECPAIRING` precompile for an input of `input_len` bytes. The gas
+/// cost saturates at [`u64::MAX`].
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip197::ec_pairing_gas;
+/// assert_eq!(ec_pairing_gas(0), 100_000);
+/// assert_eq!(ec_pairing_gas(2 * 192), 260_000);
+/// ```
+#[must_use]
+#[inline]
+pub const fn ec_pairing_gas(input_len: usize) -> u64 {
+    EC_PAIRING_PER_POINT_GAS
+        .saturating_mul((input_len / EC_PAIRING_POINT_SIZE) as u64)
+        .saturating_add(EC_PAIRING_BASE_GAS)
+}
 
 /// EIP-197: Precompiled contracts for optimal ate pairing check on the elliptic curve `alt_bn128`.
 pub struct Eip197;
 
 impl Eip for Eip197 {
     const NUMBER: u32 = 197;
+    const TITLE: &'static str =
+        "Precompiled contracts for optimal ate pairing check on the elliptic curve `alt_bn128`";
 }
+
+display_eip!(Eip197);
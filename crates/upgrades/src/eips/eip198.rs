@@ -100,11 +100,145 @@
 //!
 //! Vitalik Buterin (@vbuterin), "EIP-198: Big integer modular exponentiation," Ethereum Improvement Proposals, no. 198, January 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-198>.
 
-use crate::eip::Eip;
+use primitives::Address;
+
+use crate::eip::{Eip, macros::display_eip};
+
+/// Address of the `MODEXP` precompile.
+pub const MODEXP_ADDRESS: Address = Address::precompile(0x05);
+
+/// Divisor of the `MODEXP` gas cost.
+pub const GQUADDIVISOR: u64 = 20;
+
+/// Returns the `ADJUSTED_EXPONENT_LENGTH` of an exponent that is `exp_len` bytes long, which is
+/// roughly the index of its highest set bit.
+///
+/// `exponent_head` holds the first `min(exp_len, 32)` bytes of the exponent. Missing bytes are
+/// treated as zeros, like the precompile does for input that is too short. The length saturates
+/// at [`u64::MAX`].
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip198::adjusted_exponent_length;
+/// assert_eq!(adjusted_exponent_length(1, &[0x00]), 0);
+/// assert_eq!(adjusted_exponent_length(2, &[0x01, 0x00]), 8);
+/// assert_eq!(adjusted_exponent_length(100, &[0x00, 0x00, 0x01]), 8 * 68 + 232);
+/// ```
+#[must_use]
+pub fn adjusted_exponent_length(exp_len: u64, exponent_head: &[u8]) -> u64 {
+    // Bounded by 32, so the casts are lossless.
+    let head_len = exp_len.min(32) as usize;
+    let head = &exponent_head[..exponent_head.len().min(head_len)];
+
+    let highest_bit = head.iter().position(|&byte| byte != 0).map_or(0, |index| {
+        (head_len - 1 - index) as u64 * 8 + u64::from(head[index].ilog2())
+    });
+
+    exp_len
+        .saturating_sub(32)
+        .saturating_mul(8)
+        .saturating_add(highest_bit)
+}
+
+/// Approximates the difficulty of Karatsuba multiplication of `x` byte numbers.
+const fn mult_complexity(x: u64) -> u64 {
+    let square = x.saturating_mul(x);
+
+    if x <= 64 {
+        square
+    } else if x <= 1024 {
+        square / 4 + 96 * x - 3072
+    } else {
+        (square / 16)
+            .saturating_add(x.saturating_mul(480))
+            .saturating_sub(199_680)
+    }
+}
+
+/// Returns the gas cost of the `MODEXP` precompile, which was reduced by
+/// [EIP-2565](super::eip2565::modexp_gas). The gas cost saturates at [`u64::MAX`].
+///
+/// `exponent_head` holds the first `min(exp_len, 32)` bytes of the exponent, see
+/// [`adjusted_exponent_length`].
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip198::modexp_gas;
+/// // 3 ** (2 ** 256 - 2 ** 32 - 978) % (2 ** 256 - 2 ** 32 - 977)
+/// let mut exponent = [0xff; 32];
+/// exponent[27] = 0xfe;
+/// exponent[30] = 0xfc;
+/// exponent[31] = 0x2e;
+///
+/// assert_eq!(modexp_gas(1, 32, 32, &exponent), 13056);
+/// ```
+#[must_use]
+pub fn modexp_gas(base_len: u64, exp_len: u64, mod_len: u64, exponent_head: &[u8]) -> u64 {
+    let complexity = mult_complexity(base_len.max(mod_len));
+    let iterations = adjusted_exponent_length(exp_len, exponent_head).max(1);
+
+    complexity.saturating_mul(iterations) / GQUADDIVISOR
+}
 
 /// EIP-198: Big integer modular exponentiation.
 pub struct Eip198;
 
 impl Eip for Eip198 {
     const NUMBER: u32 = 198;
+    const TITLE: &'static str = "Big integer modular exponentiation";
+}
+
+display_eip!(Eip198);
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// `(base_len, exponent, mod_len, EIP-198 gas, EIP-2565 gas)` of the `modexp_nagydani` test
+    /// vectors listed in EIP-2565.
+    pub(crate) const NAGYDANI_VECTORS: [(u64, &[u8], u64, u64, u64); 15] = [
+        (64, &[0x02], 64, 204, 200),
+        (64, &[0x03], 64, 204, 200),
+        (64, &[0x01, 0x00, 0x01], 64, 3276, 341),
+        (128, &[0x02], 128, 665, 200),
+        (128, &[0x03], 128, 665, 200),
+        (128, &[0x01, 0x00, 0x01], 128, 10649, 1365),
+        (256, &[0x02], 256, 1894, 341),
+        (256, &[0x03], 256, 1894, 341),
+        (256, &[0x01, 0x00, 0x01], 256, 30310, 5461),
+        (512, &[0x02], 512, 5580, 1365),
+        (512, &[0x03], 512, 5580, 1365),
+        (512, &[0x01, 0x00, 0x01], 512, 89292, 21845),
+        (1024, &[0x02], 1024, 17868, 5461),
+        (1024, &[0x03], 1024, 17868, 5461),
+        (1024, &[0x01, 0x00, 0x01], 1024, 285_900, 87381),
+    ];
+
+    #[test]
+    fn nagydani_vectors() {
+        for (base_len, exponent, mod_len, gas, _) in NAGYDANI_VECTORS {
+            assert_eq!(
+                modexp_gas(base_len, exponent.len() as u64, mod_len, exponent),
+                gas,
+                "{base_len} {exponent:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn adjusted_exponent_lengths() {
+        assert_eq!(adjusted_exponent_length(0, &[]), 0);
+        assert_eq!(adjusted_exponent_length(32, &[0; 32]), 0);
+        assert_eq!(adjusted_exponent_length(1, &[0xff]), 7);
+        assert_eq!(adjusted_exponent_length(2, &[0x01]), 8);
+        assert_eq!(adjusted_exponent_length(33, &[0; 32]), 8);
+        assert_eq!(adjusted_exponent_length(33, &[0x80]), 8 + 255);
+        assert_eq!(adjusted_exponent_length(u64::MAX, &[]), u64::MAX);
+
+        assert_eq!(modexp_gas(0, 0, 0, &[]), 0);
+        assert_eq!(
+            modexp_gas(u64::MAX, 32, 0, &[0xff; 32]),
+            u64::MAX / GQUADDIVISOR
+        );
+    }
 }
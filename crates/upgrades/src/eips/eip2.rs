@@ -38,11 +38,14 @@
 //!
 //! Vitalik Buterin (@vbuterin), "EIP-2: Homestead Hard-fork Changes," Ethereum Improvement Proposals, no. 2, November 2015. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-2: Homestead Hard-fork Changes.
 pub struct Eip2;
 
 impl Eip for Eip2 {
     const NUMBER: u32 = 2;
+    const TITLE: &'static str = "Homestead Hard-fork Changes";
 }
+
+display_eip!(Eip2);
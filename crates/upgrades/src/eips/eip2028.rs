@@ -52,11 +52,20 @@
 //!
 //! Alexey Akhunov (`@AlexeyAkhunov`), Eli Ben Sasson <eli@starkware.co>, Tom Brand <tom@starkware.co>, Louis Guthmann <louis@starkware.co>, Avihu Levy <avihu@starkware.co>, "EIP-2028: Transaction data gas cost reduction," Ethereum Improvement Proposals, no. 2028, May 2019. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2028>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+
+/// Gas charged for every non-zero byte of transaction data before EIP-2028.
+pub const LEGACY_TX_DATA_NON_ZERO_GAS: u64 = 68;
+
+/// Gas charged for every non-zero byte of transaction data as defined in [EIP-2028](`Eip2028`).
+pub const TX_DATA_NON_ZERO_GAS: u64 = 16;
 
 /// EIP-2028: Transaction data gas cost reduction.
 pub struct Eip2028;
 
 impl Eip for Eip2028 {
     const NUMBER: u32 = 2028;
+    const TITLE: &'static str = "Transaction data gas cost reduction";
 }
+
+display_eip!(Eip2028);
@@ -49,7 +49,10 @@
 //!
 //! Christian Reitwiessner <chris@ethereum.org>, "EIP-211: New opcodes: RETURNDATASIZE and RETURNDATACOPY," Ethereum Improvement Proposals, no. 211, February 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-211>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
 /// EIP-211: New opcodes: RETURNDATASIZE and RETURNDATACOPY.
@@ -57,8 +60,11 @@ pub struct Eip211;
 
 impl Eip for Eip211 {
     const NUMBER: u32 = 211;
+    const TITLE: &'static str = "New opcodes: RETURNDATASIZE and RETURNDATACOPY";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, RETURNDATASIZE, RETURNDATACOPY)
     }
 }
+
+display_eip!(Eip211);
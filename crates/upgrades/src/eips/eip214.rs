@@ -34,7 +34,10 @@
 //!
 //! Vitalik Buterin <vitalik@ethereum.org>, Christian Reitwiessner <chris@ethereum.org>, "EIP-214: New opcode STATICCALL," Ethereum Improvement Proposals, no. 214, February 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-214>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
 /// EIP-214: New opcode STATICCALL.
@@ -42,8 +45,11 @@ pub struct Eip214;
 
 impl Eip for Eip214 {
     const NUMBER: u32 = 214;
+    const TITLE: &'static str = "New opcode STATICCALL";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, STATICCALL)
     }
 }
+
+display_eip!(Eip214);
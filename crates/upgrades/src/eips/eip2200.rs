@@ -1,4 +1,4 @@
-//! EIP-220: Structured Definitions for Net Gas Metering.
+//! EIP-2200: Structured Definitions for Net Gas Metering.
 //! ## Simple Summary
 //!
 //! This is an EIP that implements net gas metering. It's a combined
@@ -302,11 +302,327 @@
 //!
 //! Wei Tang (@sorpaas), "EIP-2200: Structured Definitions for Net Gas Metering," Ethereum Improvement Proposals, no. 2200, July 2019. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2200>.
 
-use crate::eip::Eip;
+use crate::{
+    eip::{Eip, macros::display_eip},
+    eips::{eip2929, eip3529},
+};
 
-/// EIP-220: Structured Definitions for Net Gas Metering.
+/// Gas charged for an `SSTORE` that does not change a slot or writes to a dirty slot.
+pub const SLOAD_GAS: u64 = 800;
+
+/// Gas charged for an `SSTORE` that sets a clean slot from zero to non-zero.
+pub const SSTORE_SET_GAS: u64 = 20000;
+
+/// Gas charged for an `SSTORE` that changes a clean non-zero slot.
+pub const SSTORE_RESET_GAS: u64 = 5000;
+
+/// Refund for an `SSTORE` that clears a slot.
+pub const SSTORE_CLEARS_SCHEDULE: u64 = 15000;
+
+/// Parameters of the net gas metering state machine of `SSTORE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SstoreSchedule {
+    /// Cost of a no-op or a write to a dirty slot (`SLOAD_GAS`).
+    pub sload_gas: u64,
+    /// Cost of setting a clean slot from zero to non-zero (`SSTORE_SET_GAS`).
+    pub set_gas: u64,
+    /// Cost of changing a clean non-zero slot (`SSTORE_RESET_GAS`).
+    pub reset_gas: u64,
+    /// Refund for clearing a slot (`SSTORE_CLEARS_SCHEDULE`).
+    pub clears_schedule: u64,
+    /// Additional cost of accessing a cold slot.
+    pub cold_sload_cost: u64,
+}
+
+impl SstoreSchedule {
+    /// Net gas metering as originally defined in [EIP-1283](crate::eips::eip1283).
+    pub const EIP1283: Self = Self {
+        sload_gas: 200,
+        ..Self::EIP2200
+    };
+
+    /// Net gas metering as defined in [EIP-2200](`Eip2200`).
+    pub const EIP2200: Self = Self {
+        sload_gas: SLOAD_GAS,
+        set_gas: SSTORE_SET_GAS,
+        reset_gas: SSTORE_RESET_GAS,
+        clears_schedule: SSTORE_CLEARS_SCHEDULE,
+        cold_sload_cost: 0,
+    };
+
+    /// Net gas metering with the storage access costs of [EIP-2929](crate::eips::eip2929).
+    pub const EIP2929: Self = Self {
+        sload_gas: eip2929::WARM_STORAGE_READ_COST,
+        reset_gas: SSTORE_RESET_GAS - eip2929::COLD_SLOAD_COST,
+        cold_sload_cost: eip2929::COLD_SLOAD_COST,
+        ..Self::EIP2200
+    };
+
+    /// Net gas metering with the reduced refunds of [EIP-3529](crate::eips::eip3529).
+    pub const EIP3529: Self = Self {
+        clears_schedule: eip3529::SSTORE_CLEARS_SCHEDULE,
+        ..Self::EIP2929
+    };
+
+    /// Returns the gas cost of an `SSTORE` and the change of the refund counter, given the
+    /// *original* value of the slot at the start of the transaction, its *current* value and the
+    /// *new* value. `warm` tells whether the slot was already accessed in the transaction.
+    ///
+    /// The refund counter change can be negative. The check that *gasleft* is above the call
+    /// stipend is not part of the cost.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::eips::eip2200::SstoreSchedule;
+    /// let zero = [0; 32];
+    /// let one = { let mut word = [0; 32]; word[31] = 1; word };
+    ///
+    /// assert_eq!(SstoreSchedule::EIP2200.cost(zero, zero, one, true), (20000, 0));
+    /// assert_eq!(SstoreSchedule::EIP2200.cost(one, one, zero, true), (5000, 15000));
+    /// assert_eq!(SstoreSchedule::EIP2929.cost(one, one, one, false), (2200, 0));
+    /// ```
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_wrap,
+        reason = "gas schedule values are far below `i64::MAX`"
+    )]
+    pub fn cost(
+        &self,
+        original: [u8; 32],
+        current: [u8; 32],
+        new: [u8; 32],
+        warm: bool,
+    ) -> (u64, i64) {
+        let access = if warm { 0 } else { self.cold_sload_cost };
+
+        if current == new {
+            return (access + self.sload_gas, 0);
+        }
+
+        let zero = [0; 32];
+
+        if original == current {
+            return if original == zero {
+                (access + self.set_gas, 0)
+            } else if new == zero {
+                (access + self.reset_gas, self.clears_schedule as i64)
+            } else {
+                (access + self.reset_gas, 0)
+            };
+        }
+
+        let mut refund = 0;
+
+        if original != zero {
+            if current == zero {
+                refund -= self.clears_schedule as i64;
+            }
+            if new == zero {
+                refund += self.clears_schedule as i64;
+            }
+        }
+
+        if original == new {
+            let restored = if original == zero {
+                self.set_gas
+            } else {
+                self.reset_gas
+            };
+            refund += (restored - self.sload_gas) as i64;
+        }
+
+        (access + self.sload_gas, refund)
+    }
+}
+
+/// The way `SSTORE` is metered in an execution upgrade, see
+/// [`ExecutionUpgrade::sstore_metering`](crate::execution::ExecutionUpgrade::sstore_metering).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SstoreScheme {
+    /// No net gas metering: setting a zero slot costs `SSTORE_SET_GAS`, any other write costs
+    /// `SSTORE_RESET_GAS` and clearing a slot refunds `SSTORE_CLEARS_SCHEDULE`.
+    Legacy,
+    /// Net gas metering of [EIP-1283](crate::eips::eip1283).
+    Eip1283,
+    /// Net gas metering of [EIP-2200](`Eip2200`).
+    Eip2200,
+    /// Net gas metering with the storage access costs of [EIP-2929](crate::eips::eip2929).
+    Eip2929,
+    /// Net gas metering with the reduced refunds of [EIP-3529](crate::eips::eip3529).
+    Eip3529,
+}
+
+impl SstoreScheme {
+    /// Returns the net gas metering parameters of this scheme or [`None`] for
+    /// [`SstoreScheme::Legacy`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::eips::eip2200::{SstoreSchedule, SstoreScheme};
+    /// assert_eq!(SstoreScheme::Eip2200.schedule(), Some(SstoreSchedule::EIP2200));
+    /// assert_eq!(SstoreScheme::Legacy.schedule(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn schedule(self) -> Option<SstoreSchedule> {
+        match self {
+            Self::Legacy => None,
+            Self::Eip1283 => Some(SstoreSchedule::EIP1283),
+            Self::Eip2200 => Some(SstoreSchedule::EIP2200),
+            Self::Eip2929 => Some(SstoreSchedule::EIP2929),
+            Self::Eip3529 => Some(SstoreSchedule::EIP3529),
+        }
+    }
+}
+
+/// Returns the gas cost of an `SSTORE` and the change of the refund counter under the current
+/// rules ([`SstoreSchedule::EIP3529`]), see [`SstoreSchedule::cost`].
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::sstore_gas;
+/// let zero = [0; 32];
+/// let one = { let mut word = [0; 32]; word[31] = 1; word };
+///
+/// assert_eq!(sstore_gas(zero, zero, one, false), (22100, 0));
+/// assert_eq!(sstore_gas(one, one, zero, true), (2900, 4800));
+/// ```
+#[must_use]
+#[inline]
+pub fn sstore_gas(original: [u8; 32], current: [u8; 32], new: [u8; 32], warm: bool) -> (u64, i64) {
+    SstoreSchedule::EIP3529.cost(original, current, new, warm)
+}
+
+/// EIP-2200: Structured Definitions for Net Gas Metering.
 pub struct Eip2200;
 
 impl Eip for Eip2200 {
     const NUMBER: u32 = 2200;
+    const TITLE: &'static str = "Structured Definitions for Net Gas Metering";
+}
+
+display_eip!(Eip2200);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Gas of the `PUSH1` instructions of a test case with `stores` `SSTORE`s.
+    const fn push_gas(stores: u64) -> u64 {
+        stores * 2 * 3
+    }
+
+    /// Returns a storage word holding a small value.
+    fn word(value: u8) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[31] = value;
+        word
+    }
+
+    /// Runs consecutive `SSTORE`s of `values` to a slot holding `original` and returns the used
+    /// gas (including the `PUSH1`s) and the refund.
+    fn run(schedule: &SstoreSchedule, original: u8, values: &[u8]) -> (u64, i64) {
+        let mut current = original;
+        let mut gas = push_gas(values.len() as u64);
+        let mut refund = 0;
+
+        for &value in values {
+            let (cost, delta) = schedule.cost(word(original), word(current), word(value), true);
+            gas += cost;
+            refund += delta;
+            current = value;
+        }
+
+        (gas, refund)
+    }
+
+    /// `(original, values, used gas, refund)` of the test cases of EIP-1283.
+    const EIP1283_CASES: [(u8, &[u8], u64, i64); 17] = [
+        (0, &[0, 0], 412, 0),
+        (0, &[0, 1], 20212, 0),
+        (0, &[1, 0], 20212, 19800),
+        (0, &[1, 2], 20212, 0),
+        (0, &[1, 1], 20212, 0),
+        (1, &[0, 0], 5212, 15000),
+        (1, &[0, 1], 5212, 4800),
+        (1, &[0, 2], 5212, 0),
+        (1, &[2, 0], 5212, 15000),
+        (1, &[2, 3], 5212, 0),
+        (1, &[2, 1], 5212, 4800),
+        (1, &[2, 2], 5212, 0),
+        (1, &[1, 0], 5212, 15000),
+        (1, &[1, 2], 5212, 0),
+        (1, &[1, 1], 412, 0),
+        (0, &[1, 0, 1], 40218, 19800),
+        (1, &[0, 1, 0], 10218, 19800),
+    ];
+
+    /// `(original, values, used gas, refund)` of the test cases of EIP-2200.
+    const EIP2200_CASES: [(u8, &[u8], u64, i64); 17] = [
+        (0, &[0, 0], 1612, 0),
+        (0, &[0, 1], 20812, 0),
+        (0, &[1, 0], 20812, 19200),
+        (0, &[1, 2], 20812, 0),
+        (0, &[1, 1], 20812, 0),
+        (1, &[0, 0], 5812, 15000),
+        (1, &[0, 1], 5812, 4200),
+        (1, &[0, 2], 5812, 0),
+        (1, &[2, 0], 5812, 15000),
+        (1, &[2, 3], 5812, 0),
+        (1, &[2, 1], 5812, 4200),
+        (1, &[2, 2], 5812, 0),
+        (1, &[1, 0], 5812, 15000),
+        (1, &[1, 2], 5812, 0),
+        (1, &[1, 1], 1612, 0),
+        (0, &[1, 0, 1], 40818, 19200),
+        (1, &[0, 1, 0], 10818, 19200),
+    ];
+
+    #[test]
+    fn eip1283_test_cases() {
+        for (original, values, gas, refund) in EIP1283_CASES {
+            assert_eq!(
+                run(&SstoreSchedule::EIP1283, original, values),
+                (gas, refund),
+                "original {original}, values {values:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn eip2200_test_cases() {
+        for (original, values, gas, refund) in EIP2200_CASES {
+            assert_eq!(
+                run(&SstoreSchedule::EIP2200, original, values),
+                (gas, refund),
+                "original {original}, values {values:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn access_and_refund_changes() {
+        let (zero, one, two) = (word(0), word(1), word(2));
+
+        assert_eq!(
+            SstoreSchedule::EIP2929.cost(one, one, two, false),
+            (5000, 0)
+        );
+        assert_eq!(SstoreSchedule::EIP2929.cost(one, one, two, true), (2900, 0));
+        assert_eq!(
+            SstoreSchedule::EIP2929.cost(one, two, one, true),
+            (100, 2800)
+        );
+        assert_eq!(
+            SstoreSchedule::EIP2929.cost(zero, one, zero, true),
+            (100, 19900)
+        );
+
+        assert_eq!(
+            SstoreSchedule::EIP2929.cost(one, one, zero, true),
+            (2900, 15000)
+        );
+        assert_eq!(sstore_gas(one, one, zero, true), (2900, 4800));
+        assert_eq!(sstore_gas(one, zero, one, true), (100, -4800 + 2800));
+    }
 }
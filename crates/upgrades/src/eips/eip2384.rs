@@ -24,11 +24,14 @@
 //!
 //! Eric Conner (@econoar), "EIP-2384: Muir Glacier Difficulty Bomb Delay," Ethereum Improvement Proposals, no. 2384, November 2019. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2384>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-2384: Muir Glacier Difficulty Bomb Delay.
 pub struct Eip2384;
 
 impl Eip for Eip2384 {
     const NUMBER: u32 = 2384;
+    const TITLE: &'static str = "Muir Glacier Difficulty Bomb Delay";
 }
+
+display_eip!(Eip2384);
@@ -357,11 +357,48 @@
 //!
 //! Alex Vlasov (@shamatar), Kelly Olson (@ineffectualproperty), Alex Stokes (@ralexstokes), Antonio Sanso (@asanso), "EIP-2537: Precompile for BLS12-381 curve operations," Ethereum Improvement Proposals, no. 2537, February 2020. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2537>.
 
-use crate::eip::Eip;
+use primitives::Address;
+
+use crate::eip::{Eip, macros::display_eip};
+
+/// Address of the `BLS12_G1ADD` precompile.
+pub const BLS12_G1ADD_ADDRESS: Address = Address::precompile(0x0B);
+
+/// Address of the `BLS12_G1MSM` precompile.
+pub const BLS12_G1MSM_ADDRESS: Address = Address::precompile(0x0C);
+
+/// Address of the `BLS12_G2ADD` precompile.
+pub const BLS12_G2ADD_ADDRESS: Address = Address::precompile(0x0D);
+
+/// Address of the `BLS12_G2MSM` precompile.
+pub const BLS12_G2MSM_ADDRESS: Address = Address::precompile(0x0E);
+
+/// Address of the `BLS12_PAIRING_CHECK` precompile.
+pub const BLS12_PAIRING_CHECK_ADDRESS: Address = Address::precompile(0x0F);
+
+/// Address of the `BLS12_MAP_FP_TO_G1` precompile.
+pub const BLS12_MAP_FP_TO_G1_ADDRESS: Address = Address::precompile(0x10);
+
+/// Address of the `BLS12_MAP_FP2_TO_G2` precompile.
+pub const BLS12_MAP_FP2_TO_G2_ADDRESS: Address = Address::precompile(0x11);
+
+/// Addresses of all BLS12-381 precompiles, in ascending order.
+pub const BLS12_PRECOMPILE_ADDRESSES: [Address; 7] = [
+    BLS12_G1ADD_ADDRESS,
+    BLS12_G1MSM_ADDRESS,
+    BLS12_G2ADD_ADDRESS,
+    BLS12_G2MSM_ADDRESS,
+    BLS12_PAIRING_CHECK_ADDRESS,
+    BLS12_MAP_FP_TO_G1_ADDRESS,
+    BLS12_MAP_FP2_TO_G2_ADDRESS,
+];
 
 /// EIP-2537: Precompile for BLS12-381 curve operations.
 pub struct Eip2537;
 
 impl Eip for Eip2537 {
     const NUMBER: u32 = 2537;
+    const TITLE: &'static str = "Precompile for BLS12-381 curve operations";
 }
+
+display_eip!(Eip2537);
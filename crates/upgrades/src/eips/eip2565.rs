@@ -77,11 +77,60 @@
 //!
 //! Kelly Olson (@ineffectualproperty), Sean Gulley (@sean-sn), Simon Peffers (@simonatsn), Justin Drake (@justindrake), Dankrad Feist (@dankrad), "EIP-2565: `ModExp` Gas Cost," Ethereum Improvement Proposals, no. 2565, March 2020. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2565>.
 
-use crate::eip::Eip;
+use crate::{
+    eip::{Eip, macros::display_eip},
+    eips::eip198::adjusted_exponent_length,
+};
+
+/// Divisor of the `MODEXP` gas cost.
+pub const GQUADDIVISOR: u64 = 3;
+
+/// Minimum gas cost of the `MODEXP` precompile.
+pub const MIN_MODEXP_GAS: u64 = 200;
+
+/// Returns the gas cost of the `MODEXP` precompile. The gas cost saturates at [`u64::MAX`].
+///
+/// `exponent_head` holds the first `min(exp_len, 32)` bytes of the exponent, see
+/// [`adjusted_exponent_length`].
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip2565::modexp_gas;
+/// assert_eq!(modexp_gas(64, 3, 64, &[0x01, 0x00, 0x01]), 341);
+/// assert_eq!(modexp_gas(1, 1, 1, &[0x02]), 200);
+/// ```
+#[must_use]
+pub fn modexp_gas(base_len: u64, exp_len: u64, mod_len: u64, exponent_head: &[u8]) -> u64 {
+    let words = base_len.max(mod_len).div_ceil(8);
+    let complexity = words.saturating_mul(words);
+    let iterations = adjusted_exponent_length(exp_len, exponent_head).max(1);
+
+    (complexity.saturating_mul(iterations) / GQUADDIVISOR).max(MIN_MODEXP_GAS)
+}
 
 /// EIP-2565: `ModExp` Gas Cost.
 pub struct Eip2565;
 
 impl Eip for Eip2565 {
     const NUMBER: u32 = 2565;
+    const TITLE: &'static str = "`ModExp` Gas Cost";
+}
+
+display_eip!(Eip2565);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eips::eip198::tests::NAGYDANI_VECTORS;
+
+    #[test]
+    fn nagydani_vectors() {
+        for (base_len, exponent, mod_len, _, gas) in NAGYDANI_VECTORS {
+            assert_eq!(
+                modexp_gas(base_len, exponent.len() as u64, mod_len, exponent),
+                gas,
+                "{base_len} {exponent:?}"
+            );
+        }
+    }
 }
@@ -59,11 +59,14 @@
 //!
 //! Micah Zoltu (@`MicahZoltu`), "EIP-2718: Typed Transaction Envelope," Ethereum Improvement Proposals, no. 2718, June 2020. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2718>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-2718: Typed Transaction Envelope.
 pub struct Eip2718;
 
 impl Eip for Eip2718 {
     const NUMBER: u32 = 2718;
+    const TITLE: &'static str = "Typed Transaction Envelope";
 }
+
+display_eip!(Eip2718);
@@ -137,11 +137,75 @@
 //!
 //! Vitalik Buterin (@vbuterin), Martin Swende (@holiman), "EIP-2929: Gas cost increases for state access opcodes," Ethereum Improvement Proposals, no. 2929, September 2020. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2929>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+
+/// Gas charged for the first access of a storage slot in a transaction.
+pub const COLD_SLOAD_COST: u64 = 2100;
+
+/// Gas charged for the first access of an account in a transaction.
+pub const COLD_ACCOUNT_ACCESS_COST: u64 = 2600;
+
+/// Gas charged for accessing an already accessed (warm) storage slot or account.
+pub const WARM_STORAGE_READ_COST: u64 = 100;
+
+/// Returns the gas cost of an `SLOAD` of a cold or warm storage slot.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip2929::sload_gas;
+/// assert_eq!(sload_gas(true), 2100);
+/// assert_eq!(sload_gas(false), 100);
+/// ```
+#[must_use]
+#[inline]
+pub const fn sload_gas(is_cold: bool) -> u64 {
+    if is_cold {
+        COLD_SLOAD_COST
+    } else {
+        WARM_STORAGE_READ_COST
+    }
+}
+
+/// Returns the gas cost of accessing a cold or warm account with `BALANCE`, `EXTCODESIZE`,
+/// `EXTCODECOPY`, `EXTCODEHASH` or the `CALL` family of opcodes.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip2929::account_access_gas;
+/// assert_eq!(account_access_gas(true), 2600);
+/// assert_eq!(account_access_gas(false), 100);
+/// ```
+#[must_use]
+#[inline]
+pub const fn account_access_gas(is_cold: bool) -> u64 {
+    if is_cold {
+        COLD_ACCOUNT_ACCESS_COST
+    } else {
+        WARM_STORAGE_READ_COST
+    }
+}
 
 /// EIP-2929: Gas cost increases for state access opcodes.
 pub struct Eip2929;
 
 impl Eip for Eip2929 {
     const NUMBER: u32 = 2929;
+    const TITLE: &'static str = "Gas cost increases for state access opcodes";
+}
+
+display_eip!(Eip2929);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_costs() {
+        assert_eq!(COLD_SLOAD_COST, 2100);
+        assert_eq!(COLD_ACCOUNT_ACCESS_COST, 2600);
+        assert_eq!(WARM_STORAGE_READ_COST, 100);
+
+        assert_eq!(sload_gas(true), COLD_SLOAD_COST);
+        assert_eq!(account_access_gas(false), WARM_STORAGE_READ_COST);
+    }
 }
@@ -128,11 +128,50 @@
 //!
 //! Vitalik Buterin (@vbuterin), Martin Swende (@holiman), "EIP-2930: Optional access lists," Ethereum Improvement Proposals, no. 2930, August 2020. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2930>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+
+/// Intrinsic gas charged for every address in an access list.
+pub const ACCESS_LIST_ADDRESS_COST: u64 = 2400;
+
+/// Intrinsic gas charged for every storage key in an access list.
+pub const ACCESS_LIST_STORAGE_KEY_COST: u64 = 1900;
+
+/// Returns the intrinsic gas cost of an access list with `addresses` addresses and `keys` storage
+/// keys in total.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip2930::intrinsic_gas;
+/// // one address with two storage keys.
+/// assert_eq!(intrinsic_gas(1, 2), 2400 + 2 * 1900);
+/// ```
+#[must_use]
+#[inline]
+pub const fn intrinsic_gas(addresses: usize, keys: usize) -> u64 {
+    addresses as u64 * ACCESS_LIST_ADDRESS_COST + keys as u64 * ACCESS_LIST_STORAGE_KEY_COST
+}
 
 /// EIP-2930: Optional access lists.
 pub struct Eip2930;
 
 impl Eip for Eip2930 {
     const NUMBER: u32 = 2930;
+    const TITLE: &'static str = "Optional access lists";
+}
+
+display_eip!(Eip2930);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_list_costs() {
+        assert_eq!(ACCESS_LIST_ADDRESS_COST, 2400);
+        assert_eq!(ACCESS_LIST_STORAGE_KEY_COST, 1900);
+
+        assert_eq!(intrinsic_gas(0, 0), 0);
+        // two addresses, one with three storage keys and one without any.
+        assert_eq!(intrinsic_gas(2, 3), 4800 + 5700);
+    }
 }
@@ -212,11 +212,14 @@
 //!
 //! Vitalik Buterin (@vbuterin), Tomasz Stanczak (@tkstanczak), Guillaume Ballet (@gballet), Gajinder Singh (@g11tech), Tanishq Jasoria (@tanishqjasoria), Ignacio Hagopian (@jsign), Jochem Brouwer (@jochem-brouwer), Sina Mahmoodi (@s1na), "EIP-2935: Serve historical block hashes from state," Ethereum Improvement Proposals, no. 2935, September 2020. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-2935>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-2935: Serve historical block hashes from state.
 pub struct Eip2935;
 
 impl Eip for Eip2935 {
     const NUMBER: u32 = 2935;
+    const TITLE: &'static str = "Serve historical block hashes from state";
 }
+
+display_eip!(Eip2935);
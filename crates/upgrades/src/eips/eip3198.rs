@@ -52,7 +52,10 @@
 //!
 //! Abdelhamid Bakhta (@abdelhamidbakhta), Vitalik Buterin (@vbuterin), "EIP-3198: BASEFEE opcode," Ethereum Improvement Proposals, no. 3198, January 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-3198>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
 /// EIP-3198: BASEFEE opcode.
@@ -60,8 +63,11 @@ pub struct Eip3198;
 
 impl Eip for Eip3198 {
     const NUMBER: u32 = 3198;
+    const TITLE: &'static str = "BASEFEE opcode";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, BASEFEE)
     }
 }
+
+display_eip!(Eip3198);
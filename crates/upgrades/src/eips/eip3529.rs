@@ -128,11 +128,26 @@
 //!
 //! Vitalik Buterin (@vbuterin), Martin Swende (@holiman), "EIP-3529: Reduction in refunds," Ethereum Improvement Proposals, no. 3529, April 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-3529>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+
+/// Divisor of the gas used that caps the refund before EIP-3529.
+pub const LEGACY_MAX_REFUND_QUOTIENT: u64 = 2;
+
+/// Divisor of the gas used that caps the refund as defined in [EIP-3529](`Eip3529`).
+pub const MAX_REFUND_QUOTIENT: u64 = 5;
+
+/// Refund for clearing a storage slot as defined in [EIP-3529](`Eip3529`).
+pub const SSTORE_CLEARS_SCHEDULE: u64 = 4800;
+
+/// Refund for a `SELFDESTRUCT` before EIP-3529, which removed it.
+pub const LEGACY_SELFDESTRUCT_REFUND: u64 = 24000;
 
 /// EIP-3529: Reduction in refunds.
 pub struct Eip3529;
 
 impl Eip for Eip3529 {
     const NUMBER: u32 = 3529;
+    const TITLE: &'static str = "Reduction in refunds";
 }
+
+display_eip!(Eip3529);
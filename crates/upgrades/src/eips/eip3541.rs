@@ -57,11 +57,49 @@
 //!
 //! Alex Beregszaszi (@axic), Paweł Bylica (@chfast), Andrei Maiboroda (@gumb0), Alexey Akhunov (@`AlexeyAkhunov`), Christian Reitwiessner (@chriseth), Martin Swende (@holiman), "EIP-3541: Reject new contract code starting with the 0xEF byte," Ethereum Improvement Proposals, no. 3541, March 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-3541>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+
+/// The reserved first byte of contract code.
+pub const RESERVED_PREFIX: u8 = 0xEF;
+
+/// Returns [`true`] if new contract code is rejected because it starts with the `0xEF` byte.
+///
+/// Code that is written by [EIP-7702](crate::eips::eip7702) delegations also starts with `0xEF`,
+/// but it is never deployed through contract creation and is not subject to this rule.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip3541::is_rejected_code;
+/// assert!(is_rejected_code(&[0xEF, 0x00]));
+/// assert!(!is_rejected_code(&[0x60, 0xEF]));
+/// ```
+#[must_use]
+#[inline]
+pub const fn is_rejected_code(code: &[u8]) -> bool {
+    matches!(code, [RESERVED_PREFIX, ..])
+}
 
 /// EIP-3541: Reject new contract code starting with the 0xEF byte.
 pub struct Eip3541;
 
 impl Eip for Eip3541 {
     const NUMBER: u32 = 3541;
+    const TITLE: &'static str = "Reject new contract code starting with the 0xEF byte";
+}
+
+display_eip!(Eip3541);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejected_code() {
+        assert!(is_rejected_code(&[0xEF]));
+        assert!(is_rejected_code(&[0xEF, 0x01, 0x00]));
+
+        assert!(!is_rejected_code(&[]));
+        assert!(!is_rejected_code(&[0xFE]));
+        assert!(!is_rejected_code(&[0x60, 0x80, 0x60, 0x40, 0x52]));
+    }
 }
@@ -47,11 +47,14 @@
 //!
 //! James Hancock (@madeoftin), "EIP-3554: Difficulty Bomb Delay to December 2021," Ethereum Improvement Proposals, no. 3554, May 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-3554>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-3554: Difficulty Bomb Delay to December 2021.
 pub struct Eip3554;
 
 impl Eip for Eip3554 {
     const NUMBER: u32 = 3554;
+    const TITLE: &'static str = "Difficulty Bomb Delay to December 2021";
 }
+
+display_eip!(Eip3554);
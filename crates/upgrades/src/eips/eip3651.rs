@@ -31,11 +31,14 @@
 //!
 //! William Morriss (@wjmelements), "EIP-3651: Warm COINBASE," Ethereum Improvement Proposals, no. 3651, July 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-3651>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-3651: Warm COINBASE.
 pub struct Eip3651;
 
 impl Eip for Eip3651 {
     const NUMBER: u32 = 3651;
+    const TITLE: &'static str = "Warm COINBASE";
 }
+
+display_eip!(Eip3651);
@@ -326,11 +326,14 @@
 //!
 //! Mikhail Kalinin (@mkalinin), Danny Ryan (@djrtwo), Vitalik Buterin (@vbuterin), "EIP-3675: Upgrade consensus to Proof-of-Stake," Ethereum Improvement Proposals, no. 3675, July 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-3675>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-3675: Upgrade consensus to Proof-of-Stake.
 pub struct Eip3675;
 
 impl Eip for Eip3675 {
     const NUMBER: u32 = 3675;
+    const TITLE: &'static str = "Upgrade consensus to Proof-of-Stake";
 }
+
+display_eip!(Eip3675);
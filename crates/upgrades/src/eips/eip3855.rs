@@ -51,7 +51,10 @@
 //!
 //! Alex Beregszaszi (@axic), Hugo De la cruz (@hugo-dc), Paweł Bylica (@chfast), "EIP-3855: PUSH0 instruction," Ethereum Improvement Proposals, no. 3855, February 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-3855>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
 /// EIP-3855: PUSH0 instruction.
@@ -59,8 +62,11 @@ pub struct Eip3855;
 
 impl Eip for Eip3855 {
     const NUMBER: u32 = 3855;
+    const TITLE: &'static str = "PUSH0 instruction";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, PUSH0)
     }
 }
+
+display_eip!(Eip3855);
@@ -110,11 +110,53 @@
 //!
 //! Martin Holst Swende (@holiman), Paweł Bylica (@chfast), Alex Beregszaszi (@axic), Andrei Maiboroda (@gumb0), "EIP-3860: Limit and meter initcode," Ethereum Improvement Proposals, no. 3860, July 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-3860>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+
+/// Maximum initcode size in bytes as defined in [EIP-3860](`Eip3860`).
+pub const MAX_INITCODE_SIZE: usize = 2 * super::eip170::MAX_CODE_SIZE;
+
+/// Gas cost per 32-byte word of initcode as defined in [EIP-3860](`Eip3860`).
+pub const INITCODE_WORD_COST: u64 = 2;
+
+/// Returns the gas charged for initcode of `len` bytes.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip3860::initcode_word_cost;
+/// assert_eq!(initcode_word_cost(0), 0);
+/// assert_eq!(initcode_word_cost(33), 4);
+/// ```
+#[must_use]
+#[inline]
+pub const fn initcode_word_cost(len: usize) -> u64 {
+    INITCODE_WORD_COST * len.div_ceil(32) as u64
+}
 
 /// EIP-3860: Limit and meter initcode.
 pub struct Eip3860;
 
 impl Eip for Eip3860 {
     const NUMBER: u32 = 3860;
+    const TITLE: &'static str = "Limit and meter initcode";
+}
+
+display_eip!(Eip3860);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initcode_size_limit() {
+        assert_eq!(MAX_INITCODE_SIZE, 49152);
+        assert_eq!(initcode_word_cost(MAX_INITCODE_SIZE), 3072);
+    }
+
+    #[test]
+    fn initcode_cost() {
+        assert_eq!(initcode_word_cost(1), 2);
+        assert_eq!(initcode_word_cost(32), 2);
+        assert_eq!(initcode_word_cost(64), 4);
+        assert_eq!(initcode_word_cost(65), 6);
+    }
 }
@@ -1,4 +1,4 @@
-//! EIP-4345: Diffictly Bomb Delay to June 2022.
+//! EIP-4345: Difficulty Bomb Delay to June 2022.
 //!
 //! ## Abstract
 //! Starting with `FORK_BLOCK_NUMBER` the client will calculate the difficulty based on a fake block number suggesting to the client that the difficulty bomb is adjusting 10,700,000 blocks later than the actual block number.
@@ -48,11 +48,14 @@
 //!
 //! Tim Beiko (@timbeiko), James Hancock (`@MadeOfTin`), Thomas Jay Rush (@tjayrush), "EIP-4345: Difficulty Bomb Delay to June 2022," Ethereum Improvement Proposals, no. 4345, October 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-4345>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
-/// EIP-4345: Diffictly Bomb Delay to June 2022.
+/// EIP-4345: Difficulty Bomb Delay to June 2022.
 pub struct Eip4345;
 
 impl Eip for Eip4345 {
     const NUMBER: u32 = 4345;
+    const TITLE: &'static str = "Difficulty Bomb Delay to June 2022";
 }
+
+display_eip!(Eip4345);
@@ -139,11 +139,14 @@
 //!
 //! A reasonably high distance between bidding and rolling the dice attempts to leave low chance for bidders controlling a subset of validators to directly exploit their influence power. Ultimately, this chance depends on the type of the game and on a number of controlled validators. For instance, a chance of a single validator to affect a one-time game is negligible, and becomes bigger for multiple validators in a repeated game scenario.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-4399: Supplant DIFFICULTY opcode with PREVRANDAO.
 pub struct Eip4399;
 
 impl Eip for Eip4399 {
     const NUMBER: u32 = 4399;
+    const TITLE: &'static str = "Supplant DIFFICULTY opcode with PREVRANDAO";
 }
+
+display_eip!(Eip4399);
@@ -274,11 +274,14 @@
 //!
 //! Alex Stokes (@ralexstokes), Ansgar Dietrichs (@adietrichs), Danny Ryan (@djrtwo), Martin Holst Swende (@holiman), lightclient (@lightclient), "EIP-4788: Beacon block root in the EVM," Ethereum Improvement Proposals, no. 4788, February 2022. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-4788>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-4788: Beacon block root in the EVM.
 pub struct Eip4788;
 
 impl Eip for Eip4788 {
     const NUMBER: u32 = 4788;
+    const TITLE: &'static str = "Beacon block root in the EVM";
 }
+
+display_eip!(Eip4788);
@@ -425,16 +425,26 @@
 //!
 //! Vitalik Buterin (@vbuterin), Dankrad Feist (@dankrad), Diederik Loerakker (@protolambda), George Kadianakis (@asn-d6), Matt Garnett (@lightclient), Mofi Taiwo (@Inphi), Ansgar Dietrichs (@adietrichs), "EIP-4844: Shard Blob Transactions," Ethereum Improvement Proposals, no. 4844, February 2022. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-4844>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
+use primitives::Address;
+
+/// Address of the point evaluation precompile.
+pub const POINT_EVALUATION_PRECOMPILE_ADDRESS: Address = Address::precompile(0x0A);
 
 /// EIP-4844: Shard Blob Transactions.
 pub struct Eip4844;
 
 impl Eip for Eip4844 {
     const NUMBER: u32 = 4844;
+    const TITLE: &'static str = "Shard Blob Transactions";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, BLOBHASH)
     }
 }
+
+display_eip!(Eip4844);
@@ -160,11 +160,14 @@
 //!
 //! Alex Stokes (@ralexstokes), Danny Ryan (@djrtwo), "EIP-4895: Beacon chain push withdrawals as operations," Ethereum Improvement Proposals, no. 4895, March 2022. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-4895>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-4895: Beacon chain push withdrawals as operations.
 pub struct Eip4895;
 
 impl Eip for Eip4895 {
     const NUMBER: u32 = 4895;
+    const TITLE: &'static str = "Beacon chain push withdrawals as operations";
 }
+
+display_eip!(Eip4895);
@@ -47,11 +47,14 @@
 //!
 //! Tomasz Kajetan Stanczak (@tkstanczak), Eric Marti Haynes (@ericmartihaynes), Josh Klopfenstein (@joshklop), Abhimanyu Nag (`@AbhiMan1601`), "EIP-5133: Delaying Difficulty Bomb to mid-September 2022," Ethereum Improvement Proposals, no. 5133, June 2022. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-5133>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-5133: Delaying Difficulty Bomb to mid-September 2022.
 pub struct Eip5133;
 
 impl Eip for Eip5133 {
     const NUMBER: u32 = 5133;
+    const TITLE: &'static str = "Delaying Difficulty Bomb to mid-September 2022";
 }
+
+display_eip!(Eip5133);
@@ -171,7 +171,10 @@
 //!
 //! Alex Beregszaszi (@axic), Paul Dworzanski (@poemm), Jared Wasinger (@jwasinger), Casey Detrio (@cdetrio), Pawel Bylica (@chfast), Charles Cooper (@charles-cooper), "EIP-5656: MCOPY - Memory copying instruction," Ethereum Improvement Proposals, no. 5656, February 2021. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-5656>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
 /// EIP-5656: MCOPY - Memory copying instruction.
@@ -179,8 +182,11 @@ pub struct Eip5656;
 
 impl Eip for Eip5656 {
     const NUMBER: u32 = 5656;
+    const TITLE: &'static str = "MCOPY - Memory copying instruction";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, MCOPY)
     }
 }
+
+display_eip!(Eip5656);
@@ -28,11 +28,14 @@
 //!
 //! William Entriken (@fulldecent), "EIP-6049: Deprecate SELFDESTRUCT," Ethereum Improvement Proposals, no. 6049, November 2022. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-6049>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-6049: Deprecate SELFDESTRUCT.
 pub struct Eip6049;
 
 impl Eip for Eip6049 {
     const NUMBER: u32 = 6049;
+    const TITLE: &'static str = "Deprecate SELFDESTRUCT";
 }
+
+display_eip!(Eip6049);
@@ -215,11 +215,14 @@
 //!
 //! Mikhail Kalinin (@mkalinin), Danny Ryan (@djrtwo), Peter Davies (@petertdavies), "EIP-6110: Supply validator deposits on chain," Ethereum Improvement Proposals, no. 6110, December 2022. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-6110>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-6110: Supply validator deposits on chain.
 pub struct Eip6110;
 
 impl Eip for Eip6110 {
     const NUMBER: u32 = 6110;
+    const TITLE: &'static str = "Supply validator deposits on chain";
 }
+
+display_eip!(Eip6110);
@@ -52,11 +52,14 @@
 //!
 //! Afri Schoedon (@5chdn), Vitalik Buterin (@vbuterin), "EIP-649: Metropolis Difficulty Bomb Delay and Block Reward Reduction," Ethereum Improvement Proposals, no. 649, June 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-649>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-649: Metropolis Difficulty Bomb Delay and Block Reward Reduction.
 pub struct Eip649;
 
 impl Eip for Eip649 {
     const NUMBER: u32 = 649;
+    const TITLE: &'static str = "Metropolis Difficulty Bomb Delay and Block Reward Reduction";
 }
+
+display_eip!(Eip649);
@@ -18,11 +18,14 @@
 //!
 //! Nick Johnson <nick@ethereum.org>, "EIP-658: Embedding transaction status code in receipts," Ethereum Improvement Proposals, no. 658, June 2017. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-658>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-658: Embedding transaction status code in receipts.
 pub struct Eip658;
 
 impl Eip for Eip658 {
     const NUMBER: u32 = 658;
+    const TITLE: &'static str = "Embedding transaction status code in receipts";
 }
+
+display_eip!(Eip658);
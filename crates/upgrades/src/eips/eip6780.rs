@@ -66,11 +66,14 @@
 //!
 //! Guillaume Ballet (@gballet), Vitalik Buterin (@vbuterin), Dankrad Feist (@dankrad), "EIP-6780: SELFDESTRUCT only in same transaction," Ethereum Improvement Proposals, no. 6780, March 2023. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-6780>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-6780: SELFDESTRUCT only in same transaction.
 pub struct Eip6780;
 
 impl Eip for Eip6780 {
     const NUMBER: u32 = 6780;
+    const TITLE: &'static str = "SELFDESTRUCT only in same transaction";
 }
+
+display_eip!(Eip6780);
@@ -62,15 +62,21 @@
 //!
 //! Vitalik Buterin (@vbuterin), "EIP-7: DELEGATECALL," Ethereum Improvement Proposals, no. 7, November 2015. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-7>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 
 /// EIP-7: DELEGATECALL.
 pub struct Eip7;
 
 impl Eip for Eip7 {
     const NUMBER: u32 = 7;
+    const TITLE: &'static str = "DELEGATECALL";
 
     fn introduced_mnemonic(mnemonic: asm::Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, DELEGATECALL)
     }
 }
+
+display_eip!(Eip7);
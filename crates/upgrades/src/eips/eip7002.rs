@@ -718,11 +718,14 @@
 //!
 //! Danny Ryan (@djrtwo), Mikhail Kalinin (@mkalinin), Ansgar Dietrichs (@adietrichs), Hsiao-Wei Wang (@hwwhww), lightclient (@lightclient), Felix Lange (@fjl), "EIP-7002: Execution layer triggerable withdrawals," Ethereum Improvement Proposals, no. 7002, May 2023. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-7002>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-7002: Execution layer triggerable withdrawals.
 pub struct Eip7002;
 
 impl Eip for Eip7002 {
     const NUMBER: u32 = 7002;
+    const TITLE: &'static str = "Execution layer triggerable withdrawals";
 }
+
+display_eip!(Eip7002);
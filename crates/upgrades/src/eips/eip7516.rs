@@ -59,7 +59,10 @@
 //!
 //! Carl Beekhuizen (@carlbeek), "EIP-7516: BLOBBASEFEE instruction," Ethereum Improvement Proposals, no. 7516, September 2023. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-7516>.
 
-use crate::eip::{Eip, macros::introduced_mnemonics};
+use crate::eip::{
+    Eip,
+    macros::{display_eip, introduced_mnemonics},
+};
 use asm::Mnemonic;
 
 /// EIP-7516: BLOBBASEFEE instruction.
@@ -67,8 +70,11 @@ pub struct Eip7516;
 
 impl Eip for Eip7516 {
     const NUMBER: u32 = 7516;
+    const TITLE: &'static str = "BLOBBASEFEE instruction";
 
     fn introduced_mnemonic(mnemonic: Mnemonic) -> bool {
         introduced_mnemonics!(mnemonic, BLOBBASEFEE)
     }
 }
+
+display_eip!(Eip7516);
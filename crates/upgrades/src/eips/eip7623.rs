@@ -91,11 +91,14 @@
 //!
 //! Toni Wahrstätter (@nerolation), Vitalik Buterin (@vbuterin), "EIP-7623: Increase calldata cost," Ethereum Improvement Proposals, no. 7623, February 2024. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-7623>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-7623: Increase calldata cost.
 pub struct Eip7623;
 
 impl Eip for Eip7623 {
     const NUMBER: u32 = 7623;
+    const TITLE: &'static str = "Increase calldata cost";
 }
+
+display_eip!(Eip7623);
@@ -121,11 +121,14 @@
 //!
 //! lightclient (@lightclient), Felix Lange (@fjl), "EIP-7685: General purpose execution layer requests," Ethereum Improvement Proposals, no. 7685, April 2024. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-7685>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-7685: General purpose execution layer requests.
 pub struct Eip7685;
 
 impl Eip for Eip7685 {
     const NUMBER: u32 = 7685;
+    const TITLE: &'static str = "General purpose execution layer requests";
 }
+
+display_eip!(Eip7685);
@@ -559,11 +559,131 @@
 //!
 //! Vitalik Buterin (@vbuterin), Sam Wilson (@`SamWilsn`), Ansgar Dietrichs (@adietrichs), lightclient (@lightclient), "EIP-7702: Set Code for EOAs," Ethereum Improvement Proposals, no. 7702, May 2024. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-7702>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
+use primitives::Address;
+
+/// Prefix of a delegation indicator, followed by the address of the delegated code.
+pub const DELEGATION_PREFIX: [u8; 3] = [0xEF, 0x01, 0x00];
+
+/// Size of a delegation indicator in bytes.
+pub const DELEGATION_INDICATOR_SIZE: usize = DELEGATION_PREFIX.len() + 20;
+
+/// Returns the delegation indicator `0xef0100 || address` that is written as the code of an
+/// authority.
+///
+/// Delegating to the zero address clears the code of an authority instead of writing an
+/// indicator.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip7702::delegation_indicator;
+/// # use primitives::Address;
+/// let indicator = delegation_indicator(Address([0x11; 20]));
+/// assert_eq!(indicator[..3], [0xEF, 0x01, 0x00]);
+/// assert_eq!(indicator[3..], [0x11; 20]);
+/// ```
+#[must_use]
+pub const fn delegation_indicator(address: Address) -> [u8; DELEGATION_INDICATOR_SIZE] {
+    let mut indicator = [0; DELEGATION_INDICATOR_SIZE];
+    let mut i = 0;
+
+    while i < DELEGATION_PREFIX.len() {
+        indicator[i] = DELEGATION_PREFIX[i];
+        i += 1;
+    }
+
+    while i < DELEGATION_INDICATOR_SIZE {
+        indicator[i] = address.0[i - DELEGATION_PREFIX.len()];
+        i += 1;
+    }
+
+    indicator
+}
+
+/// Returns the delegated address if the code is a delegation indicator or [`None`] otherwise.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip7702::{delegation_indicator, parse_delegation};
+/// # use primitives::Address;
+/// let address = Address([0x11; 20]);
+/// assert_eq!(parse_delegation(&delegation_indicator(address)), Some(address));
+/// assert_eq!(parse_delegation(&[0x60, 0x80]), None);
+/// ```
+#[must_use]
+pub fn parse_delegation(code: &[u8]) -> Option<Address> {
+    code.strip_prefix(&DELEGATION_PREFIX)?
+        .try_into()
+        .ok()
+        .map(Address)
+}
+
+/// Returns [`true`] if the code is a delegation indicator.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eips::eip7702::{delegation_indicator, is_delegation_indicator};
+/// # use primitives::Address;
+/// assert!(is_delegation_indicator(&delegation_indicator(Address([0x11; 20]))));
+/// assert!(!is_delegation_indicator(&[0xEF, 0x01, 0x00]));
+/// ```
+#[must_use]
+#[inline]
+pub fn is_delegation_indicator(code: &[u8]) -> bool {
+    parse_delegation(code).is_some()
+}
 
 /// EIP-7702: Set Code for EOAs.
 pub struct Eip7702;
 
 impl Eip for Eip7702 {
     const NUMBER: u32 = 7702;
+    const TITLE: &'static str = "Set Code for EOAs";
+}
+
+display_eip!(Eip7702);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delegation_indicators() {
+        let mut address = Address::ZERO;
+        address.0[19] = 0x42;
+
+        let indicator = delegation_indicator(address);
+        assert_eq!(indicator.len(), 23);
+        assert_eq!(
+            indicator,
+            [
+                0xEF, 0x01, 0x00, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x42
+            ]
+        );
+        assert_eq!(parse_delegation(&indicator), Some(address));
+        assert!(is_delegation_indicator(&indicator));
+    }
+
+    #[test]
+    fn zero_address_delegation() {
+        let indicator = delegation_indicator(Address::ZERO);
+        assert_eq!(indicator[..3], DELEGATION_PREFIX);
+        assert_eq!(parse_delegation(&indicator), Some(Address::ZERO));
+
+        // a cleared authority has no code.
+        assert_eq!(parse_delegation(&[]), None);
+    }
+
+    #[test]
+    fn malformed_delegations() {
+        let indicator = delegation_indicator(Address([0x11; 20]));
+
+        assert!(!is_delegation_indicator(&indicator[..22]));
+        assert!(!is_delegation_indicator(
+            &[indicator.as_slice(), &[0x00]].concat()
+        ));
+        assert!(!is_delegation_indicator(
+            &[&[0xEF, 0x00, 0x00], &indicator[3..]].concat()
+        ));
+    }
 }
@@ -56,11 +56,14 @@
 //!
 //! lightclient (@lightclient), "EIP-7840: Add blob schedule to EL config files," Ethereum Improvement Proposals, no. 7840, December 2024. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-7840>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
 /// EIP-7840: Add blob schedule to EL config files.
 pub struct Eip7840;
 
 impl Eip for Eip7840 {
     const NUMBER: u32 = 7840;
+    const TITLE: &'static str = "Add blob schedule to EL config files";
 }
+
+display_eip!(Eip7840);
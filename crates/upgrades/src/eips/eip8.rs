@@ -1,4 +1,4 @@
-//! EIP-8: devp2p Forward Compatability Requirements for Homestead.
+//! EIP-8: devp2p Forward Compatibility Requirements for Homestead.
 //!
 //! ### Abstract
 //!
@@ -377,11 +377,14 @@
 //!
 //! Felix Lange <felix@ethdev.com>, "EIP-8: devp2p Forward Compatibility Requirements for Homestead," Ethereum Improvement Proposals, no. 8, December 2015. [Online serial]. Available: <https://eips.ethereum.org/EIPS/eip-8>.
 
-use crate::eip::Eip;
+use crate::eip::{Eip, macros::display_eip};
 
-/// EIP-8: devp2p Forward Compatability Requirements for Homestead.
+/// EIP-8: devp2p Forward Compatibility Requirements for Homestead.
 pub struct Eip8;
 
 impl Eip for Eip8 {
     const NUMBER: u32 = 8;
+    const TITLE: &'static str = "devp2p Forward Compatibility Requirements for Homestead";
 }
+
+display_eip!(Eip8);
@@ -1,14 +1,28 @@
 //! Genesis state of Ethereum.
 
 use asm::Mnemonic;
+use primitives::Address;
 
 use crate::eip::{Eip, macros::introduced_mnemonics};
 
+/// Address of the `ECRECOVER` precompile.
+pub const ECRECOVER_ADDRESS: Address = Address::precompile(0x01);
+
+/// Address of the `SHA256` precompile.
+pub const SHA256_ADDRESS: Address = Address::precompile(0x02);
+
+/// Address of the `RIPEMD160` precompile.
+pub const RIPEMD160_ADDRESS: Address = Address::precompile(0x03);
+
+/// Address of the `IDENTITY` precompile.
+pub const IDENTITY_ADDRESS: Address = Address::precompile(0x04);
+
 /// Genesis state of Ethereum.
 pub struct Genesis;
 
 impl Eip for Genesis {
     const NUMBER: u32 = 0;
+    const TITLE: &'static str = "Genesis state of Ethereum";
 
     #[expect(
         clippy::too_many_lines,
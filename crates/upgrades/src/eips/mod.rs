@@ -62,3 +62,123 @@ pub mod eip7702;
 pub mod eip7840;
 pub mod eip8;
 pub mod genesis;
+
+pub use eip2200::sstore_gas;
+
+use crate::eip::Eip;
+
+/// Creates a list of `(number, title)` pairs of EIPs.
+macro_rules! eip_index {
+    ($($eip: path),+) => {
+        &[$((<$eip>::NUMBER, <$eip>::TITLE)),+]
+    };
+}
+
+/// Numbers and titles of all EIPs defined in this module, in ascending order.
+const EIPS: &[(u32, &str)] = eip_index!(
+    eip2::Eip2,
+    eip7::Eip7,
+    eip8::Eip8,
+    eip100::Eip100,
+    eip140::Eip140,
+    eip145::Eip145,
+    eip150::Eip150,
+    eip152::Eip152,
+    eip155::Eip155,
+    eip160::Eip160,
+    eip161::Eip161,
+    eip170::Eip170,
+    eip196::Eip196,
+    eip197::Eip197,
+    eip198::Eip198,
+    eip211::Eip211,
+    eip214::Eip214,
+    eip649::Eip649,
+    eip658::Eip658,
+    eip1014::Eip1014,
+    eip1052::Eip1052,
+    eip1108::Eip1108,
+    eip1153::Eip1153,
+    eip1234::Eip1234,
+    eip1283::Eip1283,
+    eip1344::Eip1344,
+    eip1559::Eip1559,
+    eip1884::Eip1884,
+    eip2028::Eip2028,
+    eip2200::Eip2200,
+    eip2384::Eip2384,
+    eip2537::Eip2537,
+    eip2565::Eip2565,
+    eip2718::Eip2718,
+    eip2929::Eip2929,
+    eip2930::Eip2930,
+    eip2935::Eip2935,
+    eip3198::Eip3198,
+    eip3529::Eip3529,
+    eip3541::Eip3541,
+    eip3554::Eip3554,
+    eip3651::Eip3651,
+    eip3675::Eip3675,
+    eip3855::Eip3855,
+    eip3860::Eip3860,
+    eip4345::Eip4345,
+    eip4399::Eip4399,
+    eip4788::Eip4788,
+    eip4844::Eip4844,
+    eip4895::Eip4895,
+    eip5133::Eip5133,
+    eip5656::Eip5656,
+    eip6049::Eip6049,
+    eip6110::Eip6110,
+    eip6780::Eip6780,
+    eip7002::Eip7002,
+    eip7516::Eip7516,
+    eip7623::Eip7623,
+    eip7685::Eip7685,
+    eip7702::Eip7702,
+    eip7840::Eip7840
+);
+
+/// Returns the numbers and titles of all EIPs defined in this crate, in ascending order.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::all_eips;
+/// assert!(all_eips().contains(&(1559, "Fee market change for ETH 1.0 chain")));
+/// ```
+#[must_use]
+#[inline]
+pub const fn all_eips() -> &'static [(u32, &'static str)] {
+    EIPS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eip_index() {
+        let eips = all_eips();
+
+        assert_eq!(eips.len(), 61);
+        assert!(eips.is_sorted_by_key(|&(number, _)| number));
+        assert!(eips.windows(2).all(|pair| pair[0].0 != pair[1].0));
+
+        for (number, title) in [
+            (eip7::Eip7::NUMBER, "DELEGATECALL"),
+            (
+                eip2200::Eip2200::NUMBER,
+                "Structured Definitions for Net Gas Metering",
+            ),
+            (eip3855::Eip3855::NUMBER, "PUSH0 instruction"),
+            (eip7702::Eip7702::NUMBER, "Set Code for EOAs"),
+        ] {
+            assert!(eips.contains(&(number, title)), "EIP-{number}");
+        }
+
+        assert!(
+            eips.iter()
+                .all(|&(number, _)| number != genesis::Genesis::NUMBER)
+        );
+    }
+}
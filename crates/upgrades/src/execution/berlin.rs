@@ -16,10 +16,12 @@ impl ExecutionUpgrade for Berlin {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use asm::instruction::Add;
+    use asm::instruction::{Add, BaseFee};
 
     #[test]
     fn instruction_support() {
         assert!(Berlin::supports_instruction(&Add));
+
+        assert!(!Berlin::supports_instruction(&BaseFee));
     }
 }
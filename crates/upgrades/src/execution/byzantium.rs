@@ -4,7 +4,7 @@ use crate::{
     eip_set,
     eips::{
         eip100::Eip100, eip140::Eip140, eip196::Eip196, eip197::Eip197, eip198::Eip198,
-        eip211::Eip211, eip214::Eip214,
+        eip211::Eip211, eip214::Eip214, eip658::Eip658,
     },
     execution::{ExecutionUpgrade, spurious_dragon::SpuriousDragon},
 };
@@ -20,13 +20,15 @@ impl ExecutionUpgrade for Byzantium {
         Eip197,
         Eip198,
         Eip211,
-        Eip214
+        Eip214,
+        Eip658
     );
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{eip_set::EipSet, hardfork::HardFork};
     use asm::instruction::{Add, DelegateCall, ReturnDataCopy, ReturnDataSize, Revert, StaticCall};
 
     #[test]
@@ -39,4 +41,29 @@ mod tests {
         assert!(Byzantium::supports_instruction(&ReturnDataCopy));
         assert!(Byzantium::supports_instruction(&StaticCall));
     }
+
+    #[test]
+    fn return_data_activation() {
+        assert!(!SpuriousDragon::supports_instruction(&ReturnDataSize));
+        assert!(!SpuriousDragon::supports_instruction(&ReturnDataCopy));
+        assert!(Byzantium::supports_instruction(&ReturnDataSize));
+        assert!(Byzantium::supports_instruction(&ReturnDataCopy));
+
+        assert!(!<SpuriousDragon as ExecutionUpgrade>::EipSet::includes::<
+            Eip211,
+        >());
+        assert!(<Byzantium as ExecutionUpgrade>::EipSet::includes::<Eip211>());
+    }
+
+    #[test]
+    fn difficulty_adjustment_registered() {
+        assert!(<Byzantium as ExecutionUpgrade>::EipSet::includes::<Eip100>());
+        assert!(<Byzantium as ExecutionUpgrade>::EipSet::contains_eip(100));
+        assert!(!<SpuriousDragon as ExecutionUpgrade>::EipSet::contains_eip(
+            100
+        ));
+
+        assert!(HardFork::Byzantium.includes_eip(100));
+        assert!(HardFork::Byzantium.eips().contains(&100));
+    }
 }
@@ -26,7 +26,10 @@ impl ExecutionUpgrade for Cancun {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use asm::instruction::{Add, BlobBaseFee, MCopy, TLoad, TStore};
+    use asm::{
+        Mnemonic, OpCode,
+        instruction::{Add, BlobBaseFee, MCopy, TLoad, TStore},
+    };
 
     #[test]
     fn instruction_support() {
@@ -37,4 +40,12 @@ mod tests {
         assert!(Cancun::supports_instruction(&MCopy));
         assert!(Cancun::supports_instruction(&BlobBaseFee));
     }
+
+    #[test]
+    fn blob_base_fee_activation() {
+        assert!(!Shanghai::supports_mnemonic(Mnemonic::BLOBBASEFEE));
+        assert!(Cancun::supports_mnemonic(Mnemonic::BLOBBASEFEE));
+        assert!(!Shanghai::supports_opcode(OpCode::from_byte(0x4a)));
+        assert!(Cancun::supports_opcode(OpCode::from_byte(0x4a)));
+    }
 }
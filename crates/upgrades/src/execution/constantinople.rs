@@ -18,6 +18,7 @@ impl ExecutionUpgrade for Constantinople {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::eip_set::EipSet;
     use asm::instruction::{Add, Create2, ExtCodeHash, Sar, Shl, Shr};
 
     #[test]
@@ -30,4 +31,27 @@ mod tests {
         assert!(Constantinople::supports_instruction(&Create2));
         assert!(Constantinople::supports_instruction(&ExtCodeHash));
     }
+
+    #[test]
+    fn bitwise_shift_activation() {
+        assert!(!Byzantium::supports_instruction(&Shl));
+        assert!(!Byzantium::supports_instruction(&Shr));
+        assert!(!Byzantium::supports_instruction(&Sar));
+
+        assert!(!<Byzantium as ExecutionUpgrade>::EipSet::includes::<Eip145>());
+        assert!(<Constantinople as ExecutionUpgrade>::EipSet::includes::<
+            Eip145,
+        >());
+    }
+
+    #[test]
+    fn create2_activation() {
+        assert!(!Byzantium::supports_instruction(&Create2));
+        assert!(Constantinople::supports_instruction(&Create2));
+
+        assert!(!<Byzantium as ExecutionUpgrade>::EipSet::includes::<Eip1014>());
+        assert!(<Constantinople as ExecutionUpgrade>::EipSet::includes::<
+            Eip1014,
+        >());
+    }
 }
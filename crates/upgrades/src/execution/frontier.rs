@@ -12,7 +12,10 @@ impl ExecutionUpgrade for Frontier {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use asm::instruction::{DelegateCall, Invalid, Stop};
+    use asm::{
+        Mnemonic,
+        instruction::{DelegateCall, Invalid, Stop},
+    };
 
     #[test]
     fn instruction_support() {
@@ -20,4 +23,29 @@ mod tests {
         assert!(Frontier::supports_instruction(&Invalid));
         assert!(!Frontier::supports_instruction(&DelegateCall));
     }
+
+    #[test]
+    fn genesis_mnemonic_support() {
+        for mnemonic in [
+            Mnemonic::ADD,
+            Mnemonic::KECCAK256,
+            Mnemonic::SSTORE,
+            Mnemonic::CALL,
+            Mnemonic::CALLCODE,
+            Mnemonic::CREATE,
+        ] {
+            assert!(Frontier::supports_mnemonic(mnemonic), "{mnemonic}");
+        }
+
+        for mnemonic in [
+            Mnemonic::DELEGATECALL,
+            Mnemonic::REVERT,
+            Mnemonic::SHL,
+            Mnemonic::CHAINID,
+            Mnemonic::BASEFEE,
+            Mnemonic::PUSH0,
+        ] {
+            assert!(!Frontier::supports_mnemonic(mnemonic), "{mnemonic}");
+        }
+    }
 }
@@ -1,8 +1,37 @@
 //! Ethereum Execution layer upgrades.
 
 use asm::{AssemblyInstruction, Mnemonic, OpCode};
+use primitives::Address;
 
-use crate::eip_set::EipSet;
+use crate::{
+    eip_set::EipSet,
+    eips::{
+        eip150::{self, Eip150},
+        eip152::{self, Eip152},
+        eip160::{self, Eip160},
+        eip170::{self, Eip170},
+        eip196::{self, Eip196},
+        eip197::{self, Eip197},
+        eip198::{self, Eip198},
+        eip658::Eip658,
+        eip1283::Eip1283,
+        eip1559::Eip1559,
+        eip1884::{self, Eip1884},
+        eip2200::{Eip2200, SstoreScheme},
+        eip2537::{self, Eip2537},
+        eip2565::{self, Eip2565},
+        eip2929::{self, Eip2929},
+        eip2930::Eip2930,
+        eip3529::{self, Eip3529},
+        eip3541::Eip3541,
+        eip3860::{self, Eip3860},
+        eip4844::{self, Eip4844},
+        eip6780::Eip6780,
+        eip7702::Eip7702,
+        genesis,
+    },
+    execution::{frontier::Frontier, prague::Prague},
+};
 
 pub mod arrow_glacier;
 pub mod berlin;
@@ -23,6 +52,46 @@ pub mod shanghai;
 pub mod spurious_dragon;
 pub mod tangerine_whistle;
 
+/// Returns the execution upgrade that was active at the genesis block of Ethereum.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::execution::{genesis, ExecutionUpgrade, frontier::Frontier};
+/// # use asm::Mnemonic;
+/// fn supports<E: ExecutionUpgrade>(_: E, mnemonic: Mnemonic) -> bool {
+///     E::supports_mnemonic(mnemonic)
+/// }
+///
+/// assert!(supports(genesis(), Mnemonic::ADD));
+/// assert!(!supports(genesis(), Mnemonic::CHAINID));
+/// ```
+#[must_use]
+#[inline]
+pub const fn genesis() -> Frontier {
+    Frontier
+}
+
+/// The newest execution upgrade. This is the single place to bump when a new upgrade is added.
+pub type Latest = Prague;
+
+/// Returns the newest execution upgrade, see [`Latest`].
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::execution::{latest, ExecutionUpgrade};
+/// # use asm::Mnemonic;
+/// fn supports<E: ExecutionUpgrade>(_: E, mnemonic: Mnemonic) -> bool {
+///     E::supports_mnemonic(mnemonic)
+/// }
+///
+/// assert!(supports(latest(), Mnemonic::PUSH0));
+/// ```
+#[must_use]
+#[inline]
+pub const fn latest() -> Latest {
+    Latest {}
+}
+
 /// Ethereum execution layer upgrade.
 pub trait ExecutionUpgrade {
     /// A set of [`Eip`]s that this execution upgrade includes.
@@ -57,6 +126,19 @@ pub trait ExecutionUpgrade {
         }
     }
 
+    /// Returns [`true`] if this upgrade supports the opcode represented by a raw byte. Unknown
+    /// bytes are never supported.
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, homestead::Homestead};
+    /// assert!(Homestead::supports_byte(0xF4));
+    /// assert!(!Homestead::supports_byte(0x0F));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn supports_byte(byte: u8) -> bool {
+        Self::supports_opcode(OpCode::from_byte(byte))
+    }
+
     #[must_use]
     #[inline]
     /// Returns [`true`] if this upgrade supports an instruction.
@@ -69,4 +151,555 @@ pub trait ExecutionUpgrade {
     fn supports_instruction<I: AssemblyInstruction>(instruction: &I) -> bool {
         Self::supports_opcode(instruction.opcode())
     }
+
+    /// Returns an iterator over all [`Mnemonic`]s supported by this upgrade.
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, shanghai::Shanghai, london::London};
+    /// # use asm::Mnemonic;
+    /// assert!(Shanghai::supported_mnemonics().any(|mnemonic| mnemonic == Mnemonic::PUSH0));
+    /// assert!(!London::supported_mnemonics().any(|mnemonic| mnemonic == Mnemonic::PUSH0));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn supported_mnemonics() -> impl Iterator<Item = Mnemonic> {
+        Mnemonic::VARIANTS
+            .iter()
+            .copied()
+            .filter(|&mnemonic| Self::supports_mnemonic(mnemonic))
+    }
+
+    /// Returns the maximum size of contract code in bytes or [`None`] if the code size is not
+    /// limited (before [EIP-170](crate::eips::eip170)).
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, homestead::Homestead, london::London};
+    /// assert_eq!(Homestead::max_code_size(), None);
+    /// assert_eq!(London::max_code_size(), Some(24576));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn max_code_size() -> Option<usize> {
+        Self::EipSet::includes::<Eip170>().then_some(eip170::MAX_CODE_SIZE)
+    }
+
+    /// Returns the maximum size of initcode in bytes or [`None`] if the initcode size is not
+    /// limited (before [EIP-3860](crate::eips::eip3860)).
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, paris::Paris, shanghai::Shanghai};
+    /// assert_eq!(Paris::max_initcode_size(), None);
+    /// assert_eq!(Shanghai::max_initcode_size(), Some(49152));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn max_initcode_size() -> Option<usize> {
+        Self::EipSet::includes::<Eip3860>().then_some(eip3860::MAX_INITCODE_SIZE)
+    }
+
+    /// Returns [`true`] if new contract code starting with the `0xEF` byte is rejected
+    /// ([EIP-3541](crate::eips::eip3541)).
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, berlin::Berlin, london::London};
+    /// assert!(!Berlin::rejects_ef_prefix());
+    /// assert!(London::rejects_ef_prefix());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn rejects_ef_prefix() -> bool {
+        Self::EipSet::includes::<Eip3541>()
+    }
+
+    /// Returns [`true`] if transaction receipts contain a status code instead of an intermediate
+    /// post-state root ([EIP-658](crate::eips::eip658)).
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, homestead::Homestead, byzantium::Byzantium};
+    /// assert!(!Homestead::receipt_has_status());
+    /// assert!(Byzantium::receipt_has_status());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn receipt_has_status() -> bool {
+        Self::EipSet::includes::<Eip658>()
+    }
+
+    /// Returns [`true`] if `SELFDESTRUCT` only deletes an account that was created in the same
+    /// transaction ([EIP-6780](crate::eips::eip6780)). Otherwise it only transfers the balance.
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, shanghai::Shanghai, cancun::Cancun};
+    /// assert!(!Shanghai::selfdestruct_only_same_tx());
+    /// assert!(Cancun::selfdestruct_only_same_tx());
+    /// ```
+    #[must_use]
+    #[inline]
+    fn selfdestruct_only_same_tx() -> bool {
+        Self::EipSet::includes::<Eip6780>()
+    }
+
+    /// Returns [`true`] if a [`Mnemonic`] is deprecated in this upgrade, i.e. it is still supported
+    /// but its original semantics were removed. `SELFDESTRUCT` is deprecated since
+    /// [EIP-6780](crate::eips::eip6780).
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, shanghai::Shanghai, cancun::Cancun};
+    /// # use asm::Mnemonic;
+    /// assert!(!Shanghai::is_deprecated(Mnemonic::SELFDESTRUCT));
+    /// assert!(Cancun::is_deprecated(Mnemonic::SELFDESTRUCT));
+    /// assert!(!Cancun::is_deprecated(Mnemonic::CALL));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn is_deprecated(mnemonic: Mnemonic) -> bool {
+        matches!(mnemonic, Mnemonic::SELFDESTRUCT) && Self::selfdestruct_only_same_tx()
+    }
+
+    /// Returns the gas charged for every byte of the `EXP` exponent, which was increased in
+    /// [EIP-160](crate::eips::eip160).
+    /// ```
+    /// # use oculars_upgrades::execution::{
+    /// #     ExecutionUpgrade, spurious_dragon::SpuriousDragon, tangerine_whistle::TangerineWhistle,
+    /// # };
+    /// assert_eq!(TangerineWhistle::exp_byte_gas(), 10);
+    /// assert_eq!(SpuriousDragon::exp_byte_gas(), 50);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn exp_byte_gas() -> u64 {
+        if Self::EipSet::includes::<Eip160>() {
+            eip160::EXP_BYTE_GAS
+        } else {
+            eip160::LEGACY_EXP_BYTE_GAS
+        }
+    }
+
+    /// Returns the static gas cost of a [`Mnemonic`] in this upgrade. State accessing opcodes are
+    /// charged with their warm access cost after [EIP-2929](crate::eips::eip2929).
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, istanbul::Istanbul, berlin::Berlin};
+    /// # use asm::Mnemonic;
+    /// assert_eq!(Istanbul::base_gas_cost(Mnemonic::SLOAD), 800);
+    /// assert_eq!(Berlin::base_gas_cost(Mnemonic::SLOAD), 100);
+    /// ```
+    #[must_use]
+    fn base_gas_cost(mnemonic: Mnemonic) -> u64 {
+        use Mnemonic::{
+            BALANCE, CALL, CALLCODE, DELEGATECALL, EXTCODECOPY, EXTCODEHASH, EXTCODESIZE,
+            SELFDESTRUCT, SLOAD, STATICCALL,
+        };
+
+        let eip150 = Self::EipSet::includes::<Eip150>();
+        let eip1884 = Self::EipSet::includes::<Eip1884>();
+
+        match mnemonic {
+            SLOAD | BALANCE | EXTCODESIZE | EXTCODECOPY | EXTCODEHASH | CALL | CALLCODE
+            | DELEGATECALL | STATICCALL
+                if Self::EipSet::includes::<Eip2929>() =>
+            {
+                eip2929::WARM_STORAGE_READ_COST
+            }
+            SLOAD if eip1884 => eip1884::SLOAD_GAS,
+            SLOAD if eip150 => eip150::SLOAD_GAS,
+            SLOAD => eip150::LEGACY_SLOAD_GAS,
+            BALANCE | EXTCODEHASH if eip1884 => eip1884::BALANCE_GAS,
+            BALANCE | EXTCODEHASH if eip150 => eip150::BALANCE_GAS,
+            BALANCE => eip150::LEGACY_BALANCE_GAS,
+            EXTCODESIZE | EXTCODECOPY if eip150 => eip150::EXTCODE_GAS,
+            EXTCODESIZE | EXTCODECOPY => eip150::LEGACY_EXTCODE_GAS,
+            CALL | CALLCODE | DELEGATECALL | STATICCALL if eip150 => eip150::CALL_GAS,
+            CALL | CALLCODE | DELEGATECALL | STATICCALL => eip150::LEGACY_CALL_GAS,
+            SELFDESTRUCT if eip150 => eip150::SELFDESTRUCT_GAS,
+            SELFDESTRUCT => eip150::LEGACY_SELFDESTRUCT_GAS,
+            _ => mnemonic.base_gas_cost(),
+        }
+    }
+
+    /// Returns the [`SstoreScheme`] that meters `SSTORE` in this upgrade. Net gas metering was
+    /// introduced in Constantinople ([EIP-1283](crate::eips::eip1283)), reverted in Petersburg and
+    /// reintroduced in Istanbul ([EIP-2200](crate::eips::eip2200)).
+    /// ```
+    /// # use oculars_upgrades::execution::{
+    /// #     ExecutionUpgrade, constantinople::Constantinople, petersburg::Petersburg,
+    /// # };
+    /// # use oculars_upgrades::eips::eip2200::SstoreScheme;
+    /// assert_eq!(Constantinople::sstore_metering(), SstoreScheme::Eip1283);
+    /// assert_eq!(Petersburg::sstore_metering(), SstoreScheme::Legacy);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn sstore_metering() -> SstoreScheme {
+        if Self::EipSet::includes::<Eip3529>() {
+            SstoreScheme::Eip3529
+        } else if Self::EipSet::includes::<Eip2929>() {
+            SstoreScheme::Eip2929
+        } else if Self::EipSet::includes::<Eip2200>() {
+            SstoreScheme::Eip2200
+        } else if Self::EipSet::includes::<Eip1283>() {
+            SstoreScheme::Eip1283
+        } else {
+            SstoreScheme::Legacy
+        }
+    }
+
+    /// Returns the maximum gas refund of a transaction that used `gas_used` gas. The refund cap was
+    /// reduced from a half to a fifth of the gas used in [EIP-3529](crate::eips::eip3529).
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, berlin::Berlin, london::London};
+    /// assert_eq!(Berlin::max_refund(100_000), 50_000);
+    /// assert_eq!(London::max_refund(100_000), 20_000);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn max_refund(gas_used: u64) -> u64 {
+        if Self::EipSet::includes::<Eip3529>() {
+            gas_used / eip3529::MAX_REFUND_QUOTIENT
+        } else {
+            gas_used / eip3529::LEGACY_MAX_REFUND_QUOTIENT
+        }
+    }
+
+    /// Returns the gas refunded for a `SELFDESTRUCT`, which was removed in
+    /// [EIP-3529](crate::eips::eip3529).
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, berlin::Berlin, london::London};
+    /// assert_eq!(Berlin::selfdestruct_refund(), 24000);
+    /// assert_eq!(London::selfdestruct_refund(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn selfdestruct_refund() -> u64 {
+        if Self::EipSet::includes::<Eip3529>() {
+            0
+        } else {
+            eip3529::LEGACY_SELFDESTRUCT_REFUND
+        }
+    }
+
+    /// Returns the [EIP-2718](crate::eips::eip2718) transaction types that are valid in this
+    /// upgrade. Legacy transactions are represented by type `0`.
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, berlin::Berlin, london::London};
+    /// assert_eq!(Berlin::transaction_types(), &[0, 1]);
+    /// assert_eq!(London::transaction_types(), &[0, 1, 2]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn transaction_types() -> &'static [u8] {
+        if Self::EipSet::includes::<Eip7702>() {
+            &[0, 1, 2, 3, 4]
+        } else if Self::EipSet::includes::<Eip4844>() {
+            &[0, 1, 2, 3]
+        } else if Self::EipSet::includes::<Eip1559>() {
+            &[0, 1, 2]
+        } else if Self::EipSet::includes::<Eip2930>() {
+            &[0, 1]
+        } else {
+            &[0]
+        }
+    }
+
+    /// Returns the addresses of the precompiled contracts that are available in this upgrade, in
+    /// ascending order.
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, byzantium::Byzantium};
+    /// # use primitives::Address;
+    /// assert!(Byzantium::precompiles().contains(&Address::precompile(0x08)));
+    /// ```
+    #[must_use]
+    fn precompiles() -> Vec<Address> {
+        let mut precompiles = vec![
+            genesis::ECRECOVER_ADDRESS,
+            genesis::SHA256_ADDRESS,
+            genesis::RIPEMD160_ADDRESS,
+            genesis::IDENTITY_ADDRESS,
+        ];
+
+        if Self::EipSet::includes::<Eip198>() {
+            precompiles.push(eip198::MODEXP_ADDRESS);
+        }
+
+        if Self::EipSet::includes::<Eip196>() {
+            precompiles.extend([eip196::EC_ADD_ADDRESS, eip196::EC_MUL_ADDRESS]);
+        }
+
+        if Self::EipSet::includes::<Eip197>() {
+            precompiles.push(eip197::EC_PAIRING_ADDRESS);
+        }
+
+        if Self::EipSet::includes::<Eip152>() {
+            precompiles.push(eip152::BLAKE2F_ADDRESS);
+        }
+
+        if Self::EipSet::includes::<Eip4844>() {
+            precompiles.push(eip4844::POINT_EVALUATION_PRECOMPILE_ADDRESS);
+        }
+
+        if Self::EipSet::includes::<Eip2537>() {
+            precompiles.extend(eip2537::BLS12_PRECOMPILE_ADDRESSES);
+        }
+
+        precompiles
+    }
+
+    /// Returns the gas cost of the `MODEXP` precompile, which was reduced in
+    /// [EIP-2565](crate::eips::eip2565). See [`eip198::modexp_gas`] for the arguments.
+    /// ```
+    /// # use oculars_upgrades::execution::{ExecutionUpgrade, berlin::Berlin, istanbul::Istanbul};
+    /// assert_eq!(Istanbul::modexp_gas(64, 3, 64, &[0x01, 0x00, 0x01]), 3276);
+    /// assert_eq!(Berlin::modexp_gas(64, 3, 64, &[0x01, 0x00, 0x01]), 341);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn modexp_gas(base_len: u64, exp_len: u64, mod_len: u64, exponent_head: &[u8]) -> u64 {
+        if Self::EipSet::includes::<Eip2565>() {
+            eip2565::modexp_gas(base_len, exp_len, mod_len, exponent_head)
+        } else {
+            eip198::modexp_gas(base_len, exp_len, mod_len, exponent_head)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::{
+        berlin::Berlin, byzantium::Byzantium, cancun::Cancun, constantinople::Constantinople,
+        homestead::Homestead, istanbul::Istanbul, london::London, petersburg::Petersburg,
+        shanghai::Shanghai, spurious_dragon::SpuriousDragon, tangerine_whistle::TangerineWhistle,
+    };
+
+    #[test]
+    fn supports_byte() {
+        assert!(London::supports_byte(0x48));
+        assert!(!London::supports_byte(0x5F));
+        assert!(!London::supports_byte(0x0C));
+        assert!(Shanghai::supports_byte(0x5F));
+    }
+
+    #[test]
+    fn sstore_metering() {
+        assert_eq!(Frontier::sstore_metering(), SstoreScheme::Legacy);
+        assert_eq!(Byzantium::sstore_metering(), SstoreScheme::Legacy);
+        assert_eq!(Constantinople::sstore_metering(), SstoreScheme::Eip1283);
+        assert_eq!(Petersburg::sstore_metering(), SstoreScheme::Legacy);
+        assert_eq!(Istanbul::sstore_metering(), SstoreScheme::Eip2200);
+        assert_eq!(Berlin::sstore_metering(), SstoreScheme::Eip2929);
+        assert_eq!(London::sstore_metering(), SstoreScheme::Eip3529);
+        assert_eq!(Latest::sstore_metering(), SstoreScheme::Eip3529);
+    }
+
+    #[test]
+    fn refunds() {
+        assert_eq!(Frontier::max_refund(21_000), 10_500);
+        assert_eq!(Berlin::max_refund(21_000), 10_500);
+        assert_eq!(London::max_refund(21_000), 4_200);
+        assert_eq!(Latest::max_refund(21_000), 4_200);
+        assert_eq!(Berlin::max_refund(1), 0);
+
+        assert_eq!(Berlin::selfdestruct_refund(), 24_000);
+        assert_eq!(London::selfdestruct_refund(), 0);
+    }
+
+    #[test]
+    fn transaction_types() {
+        assert_eq!(Frontier::transaction_types(), &[0]);
+        assert_eq!(Istanbul::transaction_types(), &[0]);
+        assert_eq!(London::transaction_types(), &[0, 1, 2]);
+        assert_eq!(Shanghai::transaction_types(), &[0, 1, 2]);
+        assert_eq!(Cancun::transaction_types(), &[0, 1, 2, 3]);
+        assert_eq!(Prague::transaction_types(), &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn alt_bn128_precompiles() {
+        assert_eq!(eip196::EC_ADD_ADDRESS, Address::precompile(0x06));
+        assert_eq!(eip196::EC_MUL_ADDRESS, Address::precompile(0x07));
+        assert_eq!(eip197::EC_PAIRING_ADDRESS, Address::precompile(0x08));
+        assert_eq!(
+            eip197::EC_PAIRING_ADDRESS.to_string(),
+            "0x0000000000000000000000000000000000000008"
+        );
+        assert_eq!(eip196::EC_ADD_GAS, 500);
+        assert_eq!(eip196::EC_MUL_GAS, 40_000);
+        assert_eq!(eip197::ec_pairing_gas(191), 100_000);
+        assert_eq!(eip197::ec_pairing_gas(192), 180_000);
+
+        assert_eq!(Frontier::precompiles().len(), 4);
+        assert_eq!(SpuriousDragon::precompiles(), Frontier::precompiles());
+
+        let byzantium = Byzantium::precompiles();
+        for address in [
+            eip196::EC_ADD_ADDRESS,
+            eip196::EC_MUL_ADDRESS,
+            eip197::EC_PAIRING_ADDRESS,
+        ] {
+            assert!(byzantium.contains(&address));
+            assert!(!SpuriousDragon::precompiles().contains(&address));
+            assert!(Latest::precompiles().contains(&address));
+        }
+        assert!(byzantium.is_sorted());
+    }
+
+    #[test]
+    fn later_precompiles() {
+        assert_eq!(eip152::BLAKE2F_ADDRESS, Address::precompile(0x09));
+        assert_eq!(
+            eip4844::POINT_EVALUATION_PRECOMPILE_ADDRESS,
+            Address::precompile(0x0A)
+        );
+        assert_eq!(eip2537::BLS12_G1ADD_ADDRESS, Address::precompile(0x0B));
+        assert_eq!(
+            eip2537::BLS12_MAP_FP2_TO_G2_ADDRESS,
+            Address::precompile(0x11)
+        );
+
+        assert_eq!(Petersburg::precompiles().len(), 8);
+        assert_eq!(Istanbul::precompiles().len(), 9);
+        assert_eq!(Shanghai::precompiles().len(), 9);
+        assert_eq!(Cancun::precompiles().len(), 10);
+        assert_eq!(Prague::precompiles().len(), 17);
+
+        let latest = Latest::precompiles();
+        assert!(latest.is_sorted());
+        assert_eq!(
+            latest,
+            (0x01..=0x11).map(Address::precompile).collect::<Vec<_>>()
+        );
+        assert_eq!(eip197::ec_pairing_gas(usize::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn modexp_precompile() {
+        assert_eq!(eip198::MODEXP_ADDRESS, Address::precompile(0x05));
+        assert!(Byzantium::precompiles().contains(&eip198::MODEXP_ADDRESS));
+        assert!(!SpuriousDragon::precompiles().contains(&eip198::MODEXP_ADDRESS));
+        assert_eq!(Byzantium::precompiles().len(), 8);
+
+        assert_eq!(Byzantium::modexp_gas(64, 1, 64, &[0x02]), 204);
+        assert_eq!(Istanbul::modexp_gas(1024, 1, 1024, &[0x03]), 17868);
+        assert_eq!(Berlin::modexp_gas(1024, 1, 1024, &[0x03]), 5461);
+        assert_eq!(Latest::modexp_gas(64, 1, 64, &[0x02]), 200);
+    }
+
+    #[test]
+    fn receipt_status() {
+        assert!(!Frontier::receipt_has_status());
+        assert!(!Homestead::receipt_has_status());
+        assert!(!SpuriousDragon::receipt_has_status());
+        assert!(Byzantium::receipt_has_status());
+        assert!(Latest::receipt_has_status());
+    }
+
+    #[test]
+    fn ef_prefix_rejection() {
+        assert!(!Frontier::rejects_ef_prefix());
+        assert!(!Berlin::rejects_ef_prefix());
+        assert!(London::rejects_ef_prefix());
+        assert!(Latest::rejects_ef_prefix());
+    }
+
+    #[test]
+    fn selfdestruct_semantics() {
+        assert!(!Frontier::selfdestruct_only_same_tx());
+        assert!(!Shanghai::selfdestruct_only_same_tx());
+        assert!(Cancun::selfdestruct_only_same_tx());
+        assert!(Latest::selfdestruct_only_same_tx());
+
+        assert!(!Shanghai::is_deprecated(Mnemonic::SELFDESTRUCT));
+        assert!(Cancun::is_deprecated(Mnemonic::SELFDESTRUCT));
+        assert!(
+            Mnemonic::VARIANTS
+                .iter()
+                .filter(|&&mnemonic| Cancun::is_deprecated(mnemonic))
+                .eq([&Mnemonic::SELFDESTRUCT])
+        );
+    }
+
+    #[test]
+    fn supported_mnemonics() {
+        let frontier = Frontier::supported_mnemonics().count();
+        let london = London::supported_mnemonics().count();
+
+        assert!(frontier < london);
+        // DELEGATECALL, REVERT, RETURNDATASIZE, RETURNDATACOPY, STATICCALL, SHL, SHR, SAR,
+        // CREATE2, EXTCODEHASH, CHAINID, SELFBALANCE, BASEFEE
+        assert_eq!(london - frontier, 13);
+
+        assert_eq!(
+            Latest::supported_mnemonics().count(),
+            Mnemonic::VARIANTS.len()
+        );
+        assert!(!London::supported_mnemonics().any(|mnemonic| mnemonic == Mnemonic::PUSH0));
+        assert!(Shanghai::supported_mnemonics().any(|mnemonic| mnemonic == Mnemonic::PUSH0));
+    }
+
+    #[test]
+    fn max_code_size() {
+        assert_eq!(Frontier::max_code_size(), None);
+        assert_eq!(TangerineWhistle::max_code_size(), None);
+        assert_eq!(SpuriousDragon::max_code_size(), Some(24576));
+        assert_eq!(Latest::max_code_size(), Some(24576));
+    }
+
+    #[test]
+    fn max_initcode_size() {
+        assert_eq!(London::max_initcode_size(), None);
+        assert_eq!(Shanghai::max_initcode_size(), Some(49152));
+        assert_eq!(Cancun::max_initcode_size(), Some(49152));
+    }
+
+    #[test]
+    fn tangerine_whistle_repricing() {
+        assert_eq!(Homestead::base_gas_cost(Mnemonic::CALL), 40);
+        assert_eq!(TangerineWhistle::base_gas_cost(Mnemonic::CALL), 700);
+        assert_eq!(Homestead::base_gas_cost(Mnemonic::DELEGATECALL), 40);
+        assert_eq!(TangerineWhistle::base_gas_cost(Mnemonic::DELEGATECALL), 700);
+        assert_eq!(Homestead::base_gas_cost(Mnemonic::EXTCODESIZE), 20);
+        assert_eq!(TangerineWhistle::base_gas_cost(Mnemonic::EXTCODESIZE), 700);
+        assert_eq!(Homestead::base_gas_cost(Mnemonic::SELFDESTRUCT), 0);
+        assert_eq!(
+            TangerineWhistle::base_gas_cost(Mnemonic::SELFDESTRUCT),
+            5000
+        );
+    }
+
+    #[test]
+    fn exp_byte_gas() {
+        assert_eq!(Frontier::exp_byte_gas(), 10);
+        assert_eq!(TangerineWhistle::exp_byte_gas(), 10);
+        assert_eq!(SpuriousDragon::exp_byte_gas(), 50);
+        assert_eq!(Latest::exp_byte_gas(), 50);
+    }
+
+    #[test]
+    fn base_gas_cost() {
+        assert_eq!(Frontier::base_gas_cost(Mnemonic::SLOAD), 50);
+        assert_eq!(TangerineWhistle::base_gas_cost(Mnemonic::SLOAD), 200);
+        assert_eq!(Istanbul::base_gas_cost(Mnemonic::SLOAD), 800);
+        assert_eq!(Berlin::base_gas_cost(Mnemonic::SLOAD), 100);
+
+        assert_eq!(Frontier::base_gas_cost(Mnemonic::BALANCE), 20);
+        assert_eq!(TangerineWhistle::base_gas_cost(Mnemonic::BALANCE), 400);
+        assert_eq!(Istanbul::base_gas_cost(Mnemonic::BALANCE), 700);
+        assert_eq!(Berlin::base_gas_cost(Mnemonic::BALANCE), 100);
+
+        assert_eq!(Istanbul::base_gas_cost(Mnemonic::EXTCODESIZE), 700);
+        assert_eq!(Berlin::base_gas_cost(Mnemonic::EXTCODECOPY), 100);
+        assert_eq!(Berlin::base_gas_cost(Mnemonic::EXTCODEHASH), 100);
+        assert_eq!(Istanbul::base_gas_cost(Mnemonic::STATICCALL), 700);
+        assert_eq!(Berlin::base_gas_cost(Mnemonic::DELEGATECALL), 100);
+
+        assert_eq!(Frontier::base_gas_cost(Mnemonic::SELFDESTRUCT), 0);
+        assert_eq!(Berlin::base_gas_cost(Mnemonic::SELFDESTRUCT), 5000);
+
+        for mnemonic in Mnemonic::VARIANTS {
+            assert_eq!(Latest::base_gas_cost(*mnemonic), mnemonic.base_gas_cost());
+        }
+    }
+
+    #[test]
+    fn latest_upgrade() {
+        fn supports<E: ExecutionUpgrade>(_: E, mnemonic: Mnemonic) -> bool {
+            E::supports_mnemonic(mnemonic)
+        }
+
+        assert!(supports(latest(), Mnemonic::PUSH0));
+        assert!(supports(latest(), Mnemonic::BLOBBASEFEE));
+        assert_eq!(Latest::transaction_types(), Prague::transaction_types());
+    }
 }
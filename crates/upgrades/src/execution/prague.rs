@@ -30,6 +30,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[expect(clippy::too_many_lines, reason = "every mnemonic is checked")]
     fn instruction_support() {
         macro_rules! supports_mnemonics {
             ($($mnemonic: ident),+) => {
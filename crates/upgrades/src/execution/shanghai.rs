@@ -16,11 +16,17 @@ impl ExecutionUpgrade for Shanghai {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use asm::{Mnemonic, instruction::Add};
+    use asm::{
+        Mnemonic,
+        instruction::{Add, BlobBaseFee, BlobHash},
+    };
 
     #[test]
     fn instruction_support() {
         assert!(Shanghai::supports_instruction(&Add));
         assert!(Shanghai::supports_mnemonic(Mnemonic::PUSH0));
+
+        assert!(!Shanghai::supports_instruction(&BlobHash));
+        assert!(!Shanghai::supports_instruction(&BlobBaseFee));
     }
 }
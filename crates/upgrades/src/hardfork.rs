@@ -0,0 +1,572 @@
+//! Hard fork schedules of Ethereum chains.
+
+use std::fmt::{self, Display};
+
+use asm::Mnemonic;
+
+use crate::{
+    eip_set::EipSet,
+    execution::{
+        ExecutionUpgrade, arrow_glacier::ArrowGlacier, berlin::Berlin, byzantium::Byzantium,
+        cancun::Cancun, constantinople::Constantinople, frontier::Frontier,
+        frontier_thawing::FrontierThawing, gray_glacier::GrayGlacier, homestead::Homestead,
+        istanbul::Istanbul, london::London, muir_glacier::MuirGlacier, paris::Paris,
+        petersburg::Petersburg, prague::Prague, shanghai::Shanghai,
+        spurious_dragon::SpuriousDragon, tangerine_whistle::TangerineWhistle,
+    },
+};
+use chains::{
+    Chain, Goerli, Kovan, Mainnet, Morden, Rinkeby, Ropsten,
+    l2::{ArbitrumOne, Base, Optimism, PolygonPoS},
+};
+
+/// Calls a function that is generic over an [`ExecutionUpgrade`] with the upgrade of a
+/// [`HardFork`].
+macro_rules! with_upgrade {
+    ($fork: expr, $f: ident($($arg: expr),*)) => {
+        match $fork {
+            HardFork::Frontier => $f::<Frontier>($($arg),*),
+            HardFork::FrontierThawing => $f::<FrontierThawing>($($arg),*),
+            HardFork::Homestead => $f::<Homestead>($($arg),*),
+            HardFork::TangerineWhistle => $f::<TangerineWhistle>($($arg),*),
+            HardFork::SpuriousDragon => $f::<SpuriousDragon>($($arg),*),
+            HardFork::Byzantium => $f::<Byzantium>($($arg),*),
+            HardFork::Constantinople => $f::<Constantinople>($($arg),*),
+            HardFork::Petersburg => $f::<Petersburg>($($arg),*),
+            HardFork::Istanbul => $f::<Istanbul>($($arg),*),
+            HardFork::MuirGlacier => $f::<MuirGlacier>($($arg),*),
+            HardFork::Berlin => $f::<Berlin>($($arg),*),
+            HardFork::London => $f::<London>($($arg),*),
+            HardFork::ArrowGlacier => $f::<ArrowGlacier>($($arg),*),
+            HardFork::GrayGlacier => $f::<GrayGlacier>($($arg),*),
+            HardFork::Paris => $f::<Paris>($($arg),*),
+            HardFork::Shanghai => $f::<Shanghai>($($arg),*),
+            HardFork::Cancun => $f::<Cancun>($($arg),*),
+            HardFork::Prague => $f::<Prague>($($arg),*),
+        }
+    };
+}
+
+/// Ethereum execution layer hard fork.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HardFork {
+    /// Frontier.
+    Frontier,
+    /// Frontier Thawing.
+    FrontierThawing,
+    /// Homestead.
+    Homestead,
+    /// Tangerine Whistle.
+    TangerineWhistle,
+    /// Spurious Dragon.
+    SpuriousDragon,
+    /// Byzantium.
+    Byzantium,
+    /// Constantinople.
+    Constantinople,
+    /// Petersburg.
+    Petersburg,
+    /// Istanbul.
+    Istanbul,
+    /// Muir Glacier.
+    MuirGlacier,
+    /// Berlin.
+    Berlin,
+    /// London.
+    London,
+    /// Arrow Glacier.
+    ArrowGlacier,
+    /// Gray Glacier.
+    GrayGlacier,
+    /// Paris (The Merge).
+    Paris,
+    /// Shanghai.
+    Shanghai,
+    /// Cancun.
+    Cancun,
+    /// Prague.
+    Prague,
+}
+
+impl HardFork {
+    /// All hard forks in activation order.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::hardfork::HardFork;
+    /// assert_eq!(HardFork::VARIANTS[0], HardFork::Frontier);
+    /// assert_eq!(HardFork::VARIANTS.last(), Some(&HardFork::Prague));
+    /// ```
+    pub const VARIANTS: &[Self] = &[
+        Self::Frontier,
+        Self::FrontierThawing,
+        Self::Homestead,
+        Self::TangerineWhistle,
+        Self::SpuriousDragon,
+        Self::Byzantium,
+        Self::Constantinople,
+        Self::Petersburg,
+        Self::Istanbul,
+        Self::MuirGlacier,
+        Self::Berlin,
+        Self::London,
+        Self::ArrowGlacier,
+        Self::GrayGlacier,
+        Self::Paris,
+        Self::Shanghai,
+        Self::Cancun,
+        Self::Prague,
+    ];
+
+    /// Returns the activation of this hard fork on the chain `C` or [`None`] if the hard fork is
+    /// not defined for that chain.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::hardfork::{Activation, HardFork};
+    /// # use chains::{Mainnet, Morden};
+    /// assert_eq!(HardFork::London.activation::<Mainnet>(), Some(Activation::Block(12_965_000)));
+    /// assert_eq!(HardFork::London.activation::<Morden>(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn activation<C: Chain>(self) -> Option<Activation>
+    where
+        Self: HardForkMeta<C>,
+    {
+        HardForkMeta::<C>::activation_on_chain(self)
+    }
+
+    /// Returns the number of the first block of this hard fork on the chain `C`.
+    ///
+    /// For hard forks activated by a timestamp (Shanghai and later) this is the first block whose
+    /// timestamp reached the activation, which is only known for some chains. Returns [`None`] if
+    /// the hard fork is not defined for the chain or its first block is not known.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::hardfork::HardFork;
+    /// # use chains::{Goerli, Mainnet};
+    /// assert_eq!(HardFork::London.first_block::<Mainnet>(), Some(12_965_000));
+    /// assert_eq!(HardFork::Cancun.first_block::<Mainnet>(), Some(19_426_587));
+    /// assert_eq!(HardFork::Cancun.first_block::<Goerli>(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn first_block<C: Chain>(self) -> Option<u64>
+    where
+        Self: HardForkMeta<C>,
+    {
+        HardForkMeta::<C>::first_block_on_chain(self)
+    }
+
+    /// Returns the latest hard fork that is active at `block` on the chain `C`.
+    ///
+    /// Returns [`None`] if no hard fork is defined for that chain, or if `block` may be past a hard
+    /// fork whose first block is not known (see [`HardFork::first_block`]), in which case the
+    /// active hard fork cannot be determined from the block number alone.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::hardfork::HardFork;
+    /// # use chains::Mainnet;
+    /// assert_eq!(HardFork::at_block::<Mainnet>(12_964_999), Some(HardFork::Berlin));
+    /// assert_eq!(HardFork::at_block::<Mainnet>(12_965_000), Some(HardFork::London));
+    /// assert_eq!(HardFork::at_block::<Mainnet>(20_000_000), Some(HardFork::Cancun));
+    /// ```
+    #[must_use]
+    pub fn at_block<C: Chain>(block: u64) -> Option<Self>
+    where
+        Self: HardForkMeta<C>,
+    {
+        let mut active = None;
+
+        for &fork in Self::VARIANTS {
+            if fork.activation::<C>().is_none() {
+                continue;
+            }
+
+            match fork.first_block::<C>() {
+                Some(first) if first <= block => active = Some(fork),
+                Some(_) => break,
+                None => return None,
+            }
+        }
+
+        active
+    }
+
+    /// Returns [`true`] if this hard fork includes the EIP with the given number.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::hardfork::HardFork;
+    /// assert!(HardFork::London.includes_eip(1559));
+    /// assert!(!HardFork::Berlin.includes_eip(1559));
+    /// ```
+    #[must_use]
+    pub fn includes_eip(self, number: u32) -> bool {
+        /// Checks the EIP set of an execution upgrade.
+        fn includes<E: ExecutionUpgrade>(number: u32) -> bool {
+            E::EipSet::contains_eip(number)
+        }
+
+        with_upgrade!(self, includes(number))
+    }
+
+    /// Returns the numbers of all EIPs included in this hard fork, in ascending order.
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::hardfork::HardFork;
+    /// assert!(HardFork::London.eips().contains(&1559));
+    /// assert!(!HardFork::Berlin.eips().contains(&1559));
+    /// ```
+    #[must_use]
+    pub fn eips(self) -> Vec<u32> {
+        /// Lists the EIP set of an execution upgrade.
+        fn eips<E: ExecutionUpgrade>() -> Vec<u32> {
+            E::EipSet::eips()
+        }
+
+        with_upgrade!(self, eips())
+    }
+
+    /// Returns [`true`] if this hard fork supports a [`Mnemonic`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::hardfork::HardFork;
+    /// # use asm::Mnemonic;
+    /// assert!(HardFork::Shanghai.supports_mnemonic(Mnemonic::PUSH0));
+    /// assert!(!HardFork::London.supports_mnemonic(Mnemonic::PUSH0));
+    /// ```
+    #[must_use]
+    pub fn supports_mnemonic(self, mnemonic: Mnemonic) -> bool {
+        /// Checks the mnemonic support of an execution upgrade.
+        fn supports<E: ExecutionUpgrade>(mnemonic: Mnemonic) -> bool {
+            E::supports_mnemonic(mnemonic)
+        }
+
+        with_upgrade!(self, supports(mnemonic))
+    }
+
+    /// Returns the first hard fork that supports a [`Mnemonic`].
+    ///
+    /// # Example
+    /// ```
+    /// # use oculars_upgrades::hardfork::HardFork;
+    /// # use asm::Mnemonic;
+    /// assert_eq!(HardFork::introducing(Mnemonic::ADD), Some(HardFork::Frontier));
+    /// assert_eq!(HardFork::introducing(Mnemonic::PUSH0), Some(HardFork::Shanghai));
+    /// ```
+    #[must_use]
+    pub fn introducing(mnemonic: Mnemonic) -> Option<Self> {
+        Self::VARIANTS
+            .iter()
+            .copied()
+            .find(|fork| fork.supports_mnemonic(mnemonic))
+    }
+}
+
+/// Returns [`true`] if the EIP with the given number is active at `block` on the chain `C`.
+///
+/// Returns [`false`] if the active hard fork cannot be determined, see [`HardFork::at_block`].
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::eip_active_at_block;
+/// # use chains::Mainnet;
+/// assert!(!eip_active_at_block::<Mainnet>(1559, 12_964_999));
+/// assert!(eip_active_at_block::<Mainnet>(1559, 12_965_000));
+/// ```
+#[must_use]
+pub fn eip_active_at_block<C: Chain>(eip_number: u32, block: u64) -> bool
+where
+    HardFork: HardForkMeta<C>,
+{
+    HardFork::at_block::<C>(block).is_some_and(|fork| fork.includes_eip(eip_number))
+}
+
+impl Display for HardFork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Frontier => "Frontier",
+            Self::FrontierThawing => "Frontier Thawing",
+            Self::Homestead => "Homestead",
+            Self::TangerineWhistle => "Tangerine Whistle",
+            Self::SpuriousDragon => "Spurious Dragon",
+            Self::Byzantium => "Byzantium",
+            Self::Constantinople => "Constantinople",
+            Self::Petersburg => "Petersburg",
+            Self::Istanbul => "Istanbul",
+            Self::MuirGlacier => "Muir Glacier",
+            Self::Berlin => "Berlin",
+            Self::London => "London",
+            Self::ArrowGlacier => "Arrow Glacier",
+            Self::GrayGlacier => "Gray Glacier",
+            Self::Paris => "Paris",
+            Self::Shanghai => "Shanghai",
+            Self::Cancun => "Cancun",
+            Self::Prague => "Prague",
+        };
+
+        f.pad(name)
+    }
+}
+
+/// The point at which a hard fork activates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Activation {
+    /// Activation at a block number.
+    Block(u64),
+    /// Activation at a block timestamp.
+    Timestamp(u64),
+}
+
+impl Display for Activation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Block(block) => write!(f, "block {block}"),
+            Self::Timestamp(timestamp) => write!(f, "timestamp {timestamp}"),
+        }
+    }
+}
+
+/// Hard fork activation data for the chain `C`.
+pub trait HardForkMeta<C: Chain>: Sized {
+    /// Returns the activation of this hard fork on the chain `C` or [`None`] if the hard fork is
+    /// not defined for that chain.
+    fn activation_on_chain(self) -> Option<Activation>;
+
+    /// Returns the number of the first block of this hard fork on the chain `C` or [`None`] if it
+    /// is not known. Only hard forks activated by a block number are known by default.
+    fn first_block_on_chain(self) -> Option<u64> {
+        match HardForkMeta::<C>::activation_on_chain(self)? {
+            Activation::Block(block) => Some(block),
+            Activation::Timestamp(_) => None,
+        }
+    }
+}
+
+impl HardForkMeta<Mainnet> for HardFork {
+    fn activation_on_chain(self) -> Option<Activation> {
+        let activation = match self {
+            Self::Frontier => Activation::Block(0),
+            Self::FrontierThawing => Activation::Block(200_000),
+            Self::Homestead => Activation::Block(1_150_000),
+            Self::TangerineWhistle => Activation::Block(2_463_000),
+            Self::SpuriousDragon => Activation::Block(2_675_000),
+            Self::Byzantium => Activation::Block(4_370_000),
+            Self::Constantinople | Self::Petersburg => Activation::Block(7_280_000),
+            Self::Istanbul => Activation::Block(9_069_000),
+            Self::MuirGlacier => Activation::Block(9_200_000),
+            Self::Berlin => Activation::Block(12_244_000),
+            Self::London => Activation::Block(12_965_000),
+            Self::ArrowGlacier => Activation::Block(13_773_000),
+            Self::GrayGlacier => Activation::Block(15_050_000),
+            Self::Paris => Activation::Block(15_537_394),
+            Self::Shanghai => Activation::Timestamp(1_681_338_455),
+            Self::Cancun => Activation::Timestamp(1_710_338_135),
+            Self::Prague => Activation::Timestamp(1_746_612_311),
+        };
+
+        Some(activation)
+    }
+
+    fn first_block_on_chain(self) -> Option<u64> {
+        match (self, HardForkMeta::<Mainnet>::activation_on_chain(self)?) {
+            (Self::Shanghai, _) => Some(17_034_870),
+            (Self::Cancun, _) => Some(19_426_587),
+            (Self::Prague, _) => Some(22_431_084),
+            (_, Activation::Block(block)) => Some(block),
+            (_, Activation::Timestamp(_)) => None,
+        }
+    }
+}
+
+impl HardForkMeta<Goerli> for HardFork {
+    fn activation_on_chain(self) -> Option<Activation> {
+        match self {
+            Self::Frontier
+            | Self::Homestead
+            | Self::TangerineWhistle
+            | Self::SpuriousDragon
+            | Self::Byzantium
+            | Self::Constantinople
+            | Self::Petersburg => Some(Activation::Block(0)),
+            Self::Istanbul => Some(Activation::Block(1_561_651)),
+            Self::Berlin => Some(Activation::Block(4_460_644)),
+            Self::London => Some(Activation::Block(5_062_605)),
+            Self::Paris => Some(Activation::Block(7_382_819)),
+            Self::Shanghai => Some(Activation::Timestamp(1_678_832_736)),
+            Self::Cancun => Some(Activation::Timestamp(1_705_473_120)),
+            Self::FrontierThawing
+            | Self::MuirGlacier
+            | Self::ArrowGlacier
+            | Self::GrayGlacier
+            | Self::Prague => None,
+        }
+    }
+}
+
+/// Implements [`HardForkMeta`] for chains without hard fork activation data.
+macro_rules! undefined_hard_forks {
+    ($($chain: ident),+) => {
+        $(
+            impl HardForkMeta<$chain> for HardFork {
+                fn activation_on_chain(self) -> Option<Activation> {
+                    None
+                }
+            }
+        )+
+    };
+}
+
+undefined_hard_forks!(
+    Morden,
+    Ropsten,
+    Kovan,
+    Rinkeby,
+    Optimism,
+    Base,
+    ArbitrumOne,
+    PolygonPoS
+);
+
+/// Returns the hard fork schedule of the chain `C` in activation order.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::hardfork::{schedule, Activation, HardFork};
+/// # use chains::Mainnet;
+/// let (fork, activation) = schedule::<Mainnet>().next().unwrap();
+/// assert_eq!(fork, HardFork::Frontier);
+/// assert_eq!(activation, Some(Activation::Block(0)));
+/// ```
+pub fn schedule<C: Chain>() -> impl Iterator<Item = (HardFork, Option<Activation>)>
+where
+    HardFork: HardForkMeta<C>,
+{
+    HardFork::VARIANTS
+        .iter()
+        .map(|&fork| (fork, fork.activation::<C>()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mnemonic_introduction() {
+        assert_eq!(
+            HardFork::introducing(Mnemonic::DELEGATECALL),
+            Some(HardFork::Homestead)
+        );
+        assert_eq!(
+            HardFork::introducing(Mnemonic::CREATE2),
+            Some(HardFork::Constantinople)
+        );
+        assert_eq!(
+            HardFork::introducing(Mnemonic::BLOBHASH),
+            Some(HardFork::Cancun)
+        );
+        assert!(
+            Mnemonic::VARIANTS
+                .iter()
+                .all(|&mnemonic| HardFork::introducing(mnemonic).is_some())
+        );
+    }
+
+    #[test]
+    fn mainnet_schedule_is_ordered() {
+        let activations: Vec<_> = schedule::<Mainnet>()
+            .map(|(_, activation)| activation.unwrap())
+            .collect();
+
+        let blocks: Vec<_> = activations
+            .iter()
+            .filter_map(|activation| match activation {
+                Activation::Block(block) => Some(*block),
+                Activation::Timestamp(_) => None,
+            })
+            .collect();
+
+        assert!(blocks.is_sorted());
+        assert_eq!(blocks.len(), 15);
+        assert_eq!(
+            activations.last(),
+            Some(&Activation::Timestamp(1_746_612_311))
+        );
+    }
+
+    #[test]
+    fn undefined_schedule() {
+        assert!(schedule::<Optimism>().all(|(_, activation)| activation.is_none()));
+        assert_eq!(HardFork::Prague.activation::<Goerli>(), None);
+        assert_eq!(
+            HardFork::Shanghai.activation::<Goerli>(),
+            Some(Activation::Timestamp(1_678_832_736))
+        );
+    }
+
+    #[test]
+    fn hard_fork_at_block() {
+        assert_eq!(HardFork::at_block::<Mainnet>(0), Some(HardFork::Frontier));
+        assert_eq!(
+            HardFork::at_block::<Mainnet>(7_280_000),
+            Some(HardFork::Petersburg)
+        );
+        assert_eq!(
+            HardFork::at_block::<Mainnet>(17_034_869),
+            Some(HardFork::Paris)
+        );
+        assert_eq!(
+            HardFork::at_block::<Mainnet>(17_034_870),
+            Some(HardFork::Shanghai)
+        );
+        assert_eq!(
+            HardFork::at_block::<Mainnet>(u64::MAX),
+            Some(HardFork::Prague)
+        );
+        assert_eq!(HardFork::at_block::<Optimism>(0), None);
+
+        assert_eq!(
+            HardFork::at_block::<Goerli>(5_062_605),
+            Some(HardFork::London)
+        );
+        assert_eq!(HardFork::at_block::<Goerli>(7_382_819), None);
+    }
+
+    #[test]
+    fn london_boundary() {
+        for eip in [1559, 3198, 3529, 3541, 3554] {
+            assert!(!eip_active_at_block::<Mainnet>(eip, 12_964_999));
+            assert!(eip_active_at_block::<Mainnet>(eip, 12_965_000));
+        }
+
+        assert!(eip_active_at_block::<Mainnet>(2929, 12_964_999));
+        assert!(eip_active_at_block::<Mainnet>(4844, 20_000_000));
+        assert!(!eip_active_at_block::<Mainnet>(4844, 19_426_586));
+        assert!(!eip_active_at_block::<Optimism>(1559, 12_965_000));
+    }
+
+    #[test]
+    fn hard_fork_includes_eips() {
+        for (index, fork) in HardFork::VARIANTS.iter().enumerate() {
+            for later in &HardFork::VARIANTS[index..] {
+                for eip in [2, 7, 150, 155, 1559, 2929, 3855, 4844, 7702] {
+                    assert!(!fork.includes_eip(eip) || later.includes_eip(eip));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hard_fork_fmt() {
+        assert_eq!(HardFork::TangerineWhistle.to_string(), "Tangerine Whistle");
+        assert_eq!(format!("{:<8}|", HardFork::Paris), "Paris   |");
+        assert_eq!(Activation::Block(1).to_string(), "block 1");
+        assert_eq!(Activation::Timestamp(2).to_string(), "timestamp 2");
+    }
+}
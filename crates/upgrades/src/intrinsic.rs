@@ -0,0 +1,137 @@
+//! Intrinsic gas of transactions.
+
+use crate::{
+    eips::{eip2028, eip2930, eip3860},
+    hardfork::HardFork,
+};
+
+/// Base gas charged for every transaction.
+pub const TX_GAS: u64 = 21000;
+
+/// Additional gas charged for contract creation transactions since
+/// [EIP-2](crate::eips::eip2).
+pub const TX_CREATE_GAS: u64 = 32000;
+
+/// Gas charged for every zero byte of transaction data.
+pub const TX_DATA_ZERO_GAS: u64 = 4;
+
+/// Returns the intrinsic gas of a transaction in the hard fork `fork`, which is charged before
+/// any code is executed.
+///
+/// The cost consists of the base transaction cost, the cost of the calldata, the cost of the
+/// access list ([EIP-2930](crate::eips::eip2930)) and, for contract creation, the creation cost
+/// and the initcode word cost ([EIP-3860](crate::eips::eip3860)). For contract creation the
+/// calldata is the initcode.
+///
+/// # Note
+/// The calldata floor cost of [EIP-7623](crate::eips::eip7623) depends on the execution gas and
+/// is not included.
+///
+/// # Example
+/// ```
+/// # use oculars_upgrades::{intrinsic_gas, hardfork::HardFork};
+/// // a plain transfer.
+/// assert_eq!(intrinsic_gas(&[], 0, 0, false, HardFork::Cancun), 21000);
+/// // a call with two non-zero and two zero bytes of calldata.
+/// assert_eq!(intrinsic_gas(&[1, 0, 2, 0], 0, 0, false, HardFork::Cancun), 21000 + 2 * 16 + 2 * 4);
+/// ```
+#[must_use]
+pub fn intrinsic_gas(
+    calldata: &[u8],
+    access_addrs: usize,
+    access_keys: usize,
+    is_create: bool,
+    fork: HardFork,
+) -> u64 {
+    let non_zero_gas = if fork.includes_eip(2028) {
+        eip2028::TX_DATA_NON_ZERO_GAS
+    } else {
+        eip2028::LEGACY_TX_DATA_NON_ZERO_GAS
+    };
+
+    let calldata_gas: u64 = calldata
+        .iter()
+        .map(|&byte| {
+            if byte == 0 {
+                TX_DATA_ZERO_GAS
+            } else {
+                non_zero_gas
+            }
+        })
+        .sum();
+
+    let access_list_gas = if fork.includes_eip(2930) {
+        eip2930::intrinsic_gas(access_addrs, access_keys)
+    } else {
+        0
+    };
+
+    let create_gas = match is_create {
+        true if fork.includes_eip(3860) => {
+            TX_CREATE_GAS + eip3860::initcode_word_cost(calldata.len())
+        }
+        true if fork.includes_eip(2) => TX_CREATE_GAS,
+        _ => 0,
+    };
+
+    TX_GAS + calldata_gas + access_list_gas + create_gas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calldata_gas() {
+        assert_eq!(
+            intrinsic_gas(&[0; 10], 0, 0, false, HardFork::London),
+            21040
+        );
+        assert_eq!(
+            intrinsic_gas(&[1; 10], 0, 0, false, HardFork::London),
+            21160
+        );
+        assert_eq!(
+            intrinsic_gas(&[0, 1, 0, 0, 0xFF], 0, 0, false, HardFork::London),
+            21000 + 3 * 4 + 2 * 16
+        );
+
+        // non-zero bytes were more expensive before EIP-2028.
+        assert_eq!(
+            intrinsic_gas(&[0, 1, 0, 0, 0xFF], 0, 0, false, HardFork::Petersburg),
+            21000 + 3 * 4 + 2 * 68
+        );
+    }
+
+    #[test]
+    fn access_list_gas() {
+        assert_eq!(
+            intrinsic_gas(&[], 2, 3, false, HardFork::Berlin),
+            21000 + 2 * 2400 + 3 * 1900
+        );
+        // access lists did not exist before EIP-2930.
+        assert_eq!(intrinsic_gas(&[], 2, 3, false, HardFork::Istanbul), 21000);
+    }
+
+    #[test]
+    fn create_gas() {
+        let initcode = [0x60, 0x00, 0x60, 0x00, 0xF3];
+
+        assert_eq!(
+            intrinsic_gas(&initcode, 0, 0, true, HardFork::Frontier),
+            21000 + 2 * 4 + 3 * 68
+        );
+        assert_eq!(
+            intrinsic_gas(&initcode, 0, 0, true, HardFork::London),
+            21000 + 2 * 4 + 3 * 16 + 32000
+        );
+        assert_eq!(
+            intrinsic_gas(&initcode, 0, 0, true, HardFork::Shanghai),
+            21000 + 2 * 4 + 3 * 16 + 32000 + 2
+        );
+        assert_eq!(
+            intrinsic_gas(&[0; 33], 0, 0, true, HardFork::Cancun),
+            21000 + 33 * 4 + 32000 + 2 * 2
+        );
+    }
+}
@@ -1,6 +1,14 @@
 //! Ethereum EIPs and execution upgrades.
 
+pub mod diff;
 pub mod eip;
 pub mod eip_set;
 pub mod eips;
 pub mod execution;
+pub mod hardfork;
+pub mod intrinsic;
+
+pub use diff::diff;
+pub use eips::all_eips;
+pub use hardfork::eip_active_at_block;
+pub use intrinsic::intrinsic_gas;
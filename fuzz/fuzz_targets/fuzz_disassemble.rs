@@ -0,0 +1,30 @@
+//! Feeds arbitrary bytes into the disassembler. It must never panic and either cover the whole
+//! input with instructions or fail with an instruction disassembly error.
+
+#![no_main]
+
+use asm::AssemblyInstruction;
+use dasm::{
+    bytecode::Bytecode,
+    disassembler::{DasmError, Disassembler},
+};
+use libfuzzer_sys::fuzz_target;
+use upgrades::execution::Latest;
+
+fuzz_target!(|data: &[u8]| {
+    let bytecode = Bytecode::from(data);
+
+    match Disassembler::<Latest>::default().disassemble(&bytecode) {
+        Ok(assembly) => {
+            let size: usize = assembly
+                .instructions()
+                .iter()
+                .map(|positioned| usize::from(positioned.instruction.size()))
+                .sum();
+
+            assert_eq!(size, data.len());
+        }
+        Err(DasmError::Instruction { position, .. }) => assert!(position < data.len()),
+        Err(error) => panic!("unexpected disassembly error: {error}"),
+    }
+});